@@ -33,7 +33,7 @@ fn run_app<B: ratatui::backend::Backend>(
     loop {
         terminal
             .draw(|f| ui(f, &mut app))
-            .map_err(|e| std::io::Error::new(std::io::ErrorKind::Other, e.to_string()))?;
+            .map_err(|e| std::io::Error::other(e.to_string()))?;
 
         let timeout = tick_rate
             .checked_sub(last_tick.elapsed())
@@ -52,46 +52,13 @@ fn run_app<B: ratatui::backend::Backend>(
     }
 }
 fn ui(f: &mut ratatui::Frame, app: &mut App) {
-    let all_sets = [
-        ("ASCII", throbber_widgets_tui::ASCII),
-        ("BOX_DRAWING", throbber_widgets_tui::BOX_DRAWING),
-        ("ARROW", throbber_widgets_tui::ARROW),
-        ("DOUBLE_ARROW", throbber_widgets_tui::DOUBLE_ARROW),
-        ("VERTICAL_BLOCK", throbber_widgets_tui::VERTICAL_BLOCK),
-        ("HORIZONTAL_BLOCK", throbber_widgets_tui::HORIZONTAL_BLOCK),
-        ("QUADRANT_BLOCK", throbber_widgets_tui::QUADRANT_BLOCK),
-        (
-            "QUADRANT_BLOCK_CRACK",
-            throbber_widgets_tui::QUADRANT_BLOCK_CRACK,
-        ),
-        ("WHITE_SQUARE", throbber_widgets_tui::WHITE_SQUARE),
-        ("WHITE_CIRCLE", throbber_widgets_tui::WHITE_CIRCLE),
-        ("BLACK_CIRCLE", throbber_widgets_tui::BLACK_CIRCLE),
-        ("CLOCK", throbber_widgets_tui::CLOCK),
-        ("BRAILLE_ONE", throbber_widgets_tui::BRAILLE_ONE),
-        ("BRAILLE_SIX", throbber_widgets_tui::BRAILLE_SIX),
-        ("BRAILLE_EIGHT", throbber_widgets_tui::BRAILLE_EIGHT),
-        ("BRAILLE_DOUBLE", throbber_widgets_tui::BRAILLE_DOUBLE),
-        (
-            "BRAILLE_SIX_DOUBLE",
-            throbber_widgets_tui::BRAILLE_SIX_DOUBLE,
-        ),
-        (
-            "BRAILLE_EIGHT_DOUBLE",
-            throbber_widgets_tui::BRAILLE_EIGHT_DOUBLE,
-        ),
-        ("OGHAM_A", throbber_widgets_tui::OGHAM_A),
-        ("OGHAM_B", throbber_widgets_tui::OGHAM_B),
-        ("OGHAM_C", throbber_widgets_tui::OGHAM_C),
-        ("PARENTHESIS", throbber_widgets_tui::PARENTHESIS),
-        ("CANADIAN", throbber_widgets_tui::CANADIAN),
-    ];
+    let all_sets = throbber_widgets_tui::BUILTIN_SETS;
     let horizontal_num = 4;
     // why +1? because the first line is for title default throbber.
-    let vertical_num = 1 + (all_sets.len() + horizontal_num - 1) / horizontal_num;
+    let vertical_num = 1 + all_sets.len().div_ceil(horizontal_num);
     let verticals = ratatui::layout::Layout::default()
         .direction(ratatui::layout::Direction::Vertical)
-        .constraints(&vec![ratatui::layout::Constraint::Length(1); vertical_num])
+        .constraints(vec![ratatui::layout::Constraint::Length(1); vertical_num])
         .split(f.area());
     let default_throbber = throbber_widgets_tui::Throbber::default()
         .label("Press q to exit. This line is a default throbber (random step). The followings are incremental step.")
@@ -107,7 +74,7 @@ fn ui(f: &mut ratatui::Frame, app: &mut App) {
             chunks = Some(
                 ratatui::layout::Layout::default()
                     .direction(ratatui::layout::Direction::Horizontal)
-                    .constraints(&vec![
+                    .constraints(vec![
                         ratatui::layout::Constraint::Percentage(
                             100 / (horizontal_num as u16)
                         );
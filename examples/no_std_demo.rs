@@ -30,7 +30,7 @@ fn main() {
     }
 
     // Check final state
-    let expected_state_index = 2 + LOOP_COUNT as i8;
+    let expected_state_index = 2 + LOOP_COUNT as i64;
     let expected_last_normalized_index = match LOOP_COUNT % 4 {
         1 => 3,
         2 => 0,
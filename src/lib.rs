@@ -31,6 +31,31 @@ cargo run --example demo --release
 - Render throbber
 - With label
 - Random or specified step, also negative is possible.
+- Time-driven animation that does not depend on the redraw cadence.
+- Finish with success, failure, warning, skipped or cancelled symbol.
+- List of multiple tasks with scrolling.
+- Tree of tasks and subtasks with aggregated status.
+- Elapsed time like `1m 05s`.
+- Template string like `"{spinner:red} {msg} [{elapsed}] {pos}/{len}"`.
+- Bouncing or sweeping bar across the width.
+- Progress percentage or mini bar once the total is known.
+- Symbol sets built at runtime from owned strings.
+- Serialize and deserialize sets by name like `"BRAILLE_EIGHT"` with the `serde` feature.
+- Look up built-in or application sets by name with `SetRegistry`.
+- 82 spinners of cli-spinners like `dots` or `bouncingBar` with the `cli-spinners` feature.
+- Multi-cell frames like `"[=   ]"` padded to the set width, so the label does not jitter.
+  The width includes the outcome symbols, and the `empty` of the built-in sets is now `" "` instead of `"　"`,
+  so `WhichUse::Empty` is as wide as the other frames of the set.
+- Multi-line frames like `LARGE_CLOCK` centered in the area with the label below.
+- Scalable circle spinner drawn with braille dots by `ThrobberCircle`.
+- Colors cycling per frame like rainbow, pulse or breathing by `Palette`.
+- Label animation like shimmer, gradient or typewriter by `LabelEffect`.
+- Cycling label suffix like growing dots with the reserved width by `LabelSuffix`.
+- Label placed left, right, above or below the symbol, aligned within the area.
+- Configurable separator between the symbol and the label, and padding on both sides.
+- Label truncated at the end or in the middle with an ellipsis, or hidden in a narrow area.
+- Rich labels of `Line` or `Text`, rendered in rows and wrapped to the area.
+- Borders and titles by `Block`, and a spinning throbber in the title of a block.
 
 ## Getting Started
 
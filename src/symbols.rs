@@ -1,183 +1,960 @@
+/// Borrow the frames of a built-in Set without allocation.
+macro_rules! frames {
+    ($($symbol:expr),* $(,)?) => {
+        alloc::borrow::Cow::Borrowed(&[$(alloc::borrow::Cow::Borrowed($symbol)),*])
+    };
+}
+
 pub mod throbber {
+    use alloc::borrow::Cow;
+
     /// A set of symbols to be rendered by throbber.
+    ///
+    /// With the `serde` feature, a built-in set is (de)serialized by its name like `"BRAILLE_EIGHT"`,
+    /// and other sets as a struct of their fields.
     #[derive(Debug, Clone, PartialEq, Eq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize), serde(into = "SetDef"))]
     pub struct Set {
-        pub full: &'static str,
-        pub empty: &'static str,
-        pub symbols: &'static [&'static str],
+        pub full: Cow<'static, str>,
+        pub empty: Cow<'static, str>,
+        pub symbols: Cow<'static, [Cow<'static, str>]>,
+        /// Recommended duration of one frame for a time-driven ThrobberState.
+        pub interval: core::time::Duration,
+        /// Hold duration of each symbol.
+        ///
+        /// If empty (or shorter than symbols), `interval` is used for the missing frames.
+        pub durations: Cow<'static, [core::time::Duration]>,
+        /// Symbols and styles used when the task is finished.
+        pub outcomes: OutcomeSet,
+    }
+
+    impl Set {
+        /// Create a set at runtime, e.g. from a config file or a localized string.
+        ///
+        /// The interval is 100ms and the outcomes are OUTCOME_UNICODE.
+        /// The built-in sets can also be modified with struct update syntax.
+        ///
+        /// # Examples:
+        /// ```
+        /// use throbber_widgets_tui::Set;
+        /// let frames = "◐ ◓ ◑ ◒".split(' ').map(String::from);
+        /// let set = Set::new("●", "○", frames);
+        /// assert_eq!(set.symbols[1], "◓");
+        ///
+        /// let set = Set {
+        ///     interval: std::time::Duration::from_millis(60),
+        ///     ..throbber_widgets_tui::BRAILLE_SIX
+        /// };
+        /// assert_eq!(set.symbols.len(), 6);
+        /// ```
+        pub fn new<F, E, I, T>(full: F, empty: E, symbols: I) -> Self
+        where
+            F: Into<Cow<'static, str>>,
+            E: Into<Cow<'static, str>>,
+            I: IntoIterator<Item = T>,
+            T: Into<Cow<'static, str>>,
+        {
+            Self {
+                full: full.into(),
+                empty: empty.into(),
+                symbols: symbols.into_iter().map(Into::into).collect(),
+                interval: DEFAULT_INTERVAL,
+                durations: Cow::Borrowed(&[]),
+                outcomes: OUTCOME_UNICODE,
+            }
+        }
+
+        /// Get the maximum display width of full, empty, symbols and outcomes.
+        ///
+        /// For multi-line symbols, it is the width of the longest line.
+        ///
+        /// Throbber pads narrower symbols to this width so that the label does not jitter,
+        /// even when the throbber is finished with an outcome.
+        ///
+        /// # Examples:
+        /// ```
+        /// use throbber_widgets_tui::Set;
+        /// assert_eq!(throbber_widgets_tui::BRAILLE_SIX.width(), 1);
+        /// assert_eq!(throbber_widgets_tui::CLOCK.width(), 2);
+        /// assert_eq!(Set::new("[====]", "[    ]", ["[=   ]", "[ =  ]", "[  = ]", "[   =]"]).width(), 6);
+        ///
+        /// let set = Set {
+        ///     outcomes: throbber_widgets_tui::OUTCOME_EMOJI,
+        ///     ..throbber_widgets_tui::BRAILLE_SIX
+        /// };
+        /// assert_eq!(set.width(), 2);
+        /// ```
+        pub fn width(&self) -> usize {
+            let outcomes = &self.outcomes;
+            self.symbols
+                .iter()
+                .chain([&self.full, &self.empty])
+                .chain([
+                    &outcomes.success.symbol,
+                    &outcomes.failure.symbol,
+                    &outcomes.warning.symbol,
+                    &outcomes.skipped.symbol,
+                    &outcomes.cancelled.symbol,
+                ])
+                .map(|symbol| display_width(symbol))
+                .max()
+                .unwrap_or_default()
+        }
+
+        /// Get the maximum number of lines of full, empty and symbols.
+        ///
+        /// # Examples:
+        /// ```
+        /// assert_eq!(throbber_widgets_tui::BRAILLE_SIX.height(), 1);
+        /// assert_eq!(throbber_widgets_tui::LARGE_CLOCK.height(), 5);
+        /// ```
+        pub fn height(&self) -> usize {
+            self.symbols
+                .iter()
+                .chain([&self.full, &self.empty])
+                .map(|symbol| symbol.split('\n').count())
+                .max()
+                .unwrap_or_default()
+        }
+
+        /// Get the hold duration of the symbol at index.
+        ///
+        /// # Examples:
+        /// ```
+        /// let set = throbber_widgets_tui::VERTICAL_BLOCK;
+        /// assert_eq!(set.frame_duration(0), set.interval);
+        /// assert!(set.interval < set.frame_duration(set.symbols.len() - 1));
+        /// ```
+        pub fn frame_duration(&self, index: usize) -> core::time::Duration {
+            self.durations.get(index).copied().unwrap_or(self.interval)
+        }
+
+        /// Get the duration of one cycle of all symbols.
+        pub fn cycle_duration(&self) -> core::time::Duration {
+            (0..self.symbols.len())
+                .map(|i| self.frame_duration(i))
+                .sum()
+        }
+    }
+
+    /// Get the number of cells used to display the string, or its longest line.
+    pub(crate) fn display_width(s: &str) -> usize {
+        s.split('\n')
+            .map(|line| ratatui::text::Span::raw(line).width())
+            .max()
+            .unwrap_or_default()
+    }
+
+    const fn millis(ms: u64) -> core::time::Duration {
+        core::time::Duration::from_millis(ms)
+    }
+
+    /// Interval of a set created at runtime.
+    const DEFAULT_INTERVAL: core::time::Duration = millis(100);
+
+    /// Serialized form of Set.
+    #[cfg(feature = "serde")]
+    #[derive(serde::Serialize)]
+    #[serde(untagged)]
+    #[allow(clippy::large_enum_variant)]
+    enum SetDef {
+        /// Name of a built-in set.
+        Name(&'static str),
+        Custom(CustomSetDef),
+    }
+
+    /// Serialized form of a set other than built-ins.
+    #[cfg(feature = "serde")]
+    #[derive(serde::Serialize, serde::Deserialize)]
+    struct CustomSetDef {
+        full: Cow<'static, str>,
+        empty: Cow<'static, str>,
+        symbols: Cow<'static, [Cow<'static, str>]>,
+        #[serde(default = "default_interval")]
+        interval: core::time::Duration,
+        #[serde(default)]
+        durations: Cow<'static, [core::time::Duration]>,
+        #[serde(default = "default_outcomes")]
+        outcomes: OutcomeSet,
+    }
+
+    #[cfg(feature = "serde")]
+    fn default_interval() -> core::time::Duration {
+        DEFAULT_INTERVAL
+    }
+
+    #[cfg(feature = "serde")]
+    fn default_outcomes() -> OutcomeSet {
+        OUTCOME_UNICODE
+    }
+
+    #[cfg(feature = "serde")]
+    impl From<Set> for SetDef {
+        fn from(set: Set) -> Self {
+            if let Some((name, _)) = named_sets().find(|(_, builtin)| *builtin == set) {
+                return Self::Name(name);
+            }
+            Self::Custom(CustomSetDef {
+                full: set.full,
+                empty: set.empty,
+                symbols: set.symbols,
+                interval: set.interval,
+                durations: set.durations,
+                outcomes: set.outcomes,
+            })
+        }
+    }
+
+    /// Deserialize a set from the name of a built-in set or a struct of its fields.
+    #[cfg(feature = "serde")]
+    impl<'de> serde::Deserialize<'de> for Set {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: serde::Deserializer<'de>,
+        {
+            struct SetVisitor;
+
+            impl<'de> serde::de::Visitor<'de> for SetVisitor {
+                type Value = Set;
+
+                fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+                    formatter.write_str("the name of a built-in throbber set or a set struct")
+                }
+
+                fn visit_str<E>(self, name: &str) -> Result<Self::Value, E>
+                where
+                    E: serde::de::Error,
+                {
+                    builtin(name)
+                        .cloned()
+                        .ok_or_else(|| E::custom(alloc::format!("unknown throbber set '{name}'")))
+                }
+
+                fn visit_map<A>(self, map: A) -> Result<Self::Value, A::Error>
+                where
+                    A: serde::de::MapAccess<'de>,
+                {
+                    let def = <CustomSetDef as serde::Deserialize>::deserialize(
+                        serde::de::value::MapAccessDeserializer::new(map),
+                    )?;
+                    Ok(Set {
+                        full: def.full,
+                        empty: def.empty,
+                        symbols: def.symbols,
+                        interval: def.interval,
+                        durations: def.durations,
+                        outcomes: def.outcomes,
+                    })
+                }
+            }
+
+            deserializer.deserialize_any(SetVisitor)
+        }
+    }
+
+    #[cfg(all(test, feature = "serde"))]
+    mod serde_tests {
+        use super::*;
+
+        #[test]
+        fn set_serde_round_trip() {
+            for (name, set) in BUILTIN_SETS {
+                let json = serde_json::to_string(set).unwrap();
+                assert_eq!(json, alloc::format!("\"{name}\""));
+                assert_eq!(&serde_json::from_str::<Set>(&json).unwrap(), set);
+            }
+            let set: Set = serde_json::from_str(r#""braille_eight""#).unwrap();
+            assert_eq!(set, BRAILLE_EIGHT);
+
+            // Sets other than built-ins are serialized as a struct.
+            let set = Set::new("*", " ", ["-", "="]);
+            let json = serde_json::to_string(&set).unwrap();
+            assert_eq!(serde_json::from_str::<Set>(&json).unwrap(), set);
+            let set: Set =
+                serde_json::from_str(r#"{"full": "*", "empty": " ", "symbols": ["-", "="]}"#)
+                    .unwrap();
+            assert_eq!(set, Set::new("*", " ", ["-", "="]));
+
+            for use_type in [WhichUse::Spin, WhichUse::Outcome(Outcome::Warning)] {
+                let json = serde_json::to_string(&use_type).unwrap();
+                assert_eq!(serde_json::from_str::<WhichUse>(&json).unwrap(), use_type);
+            }
+        }
+
+        #[test]
+        fn set_serde_unknown_name() {
+            use alloc::string::ToString;
+            let err = serde_json::from_str::<Set>(r#""NO_SUCH_SET""#).unwrap_err();
+            assert!(err
+                .to_string()
+                .starts_with("unknown throbber set 'NO_SUCH_SET'"));
+            assert!(serde_json::from_str::<Set>(r#"{"full": "*"}"#).is_err());
+        }
     }
 
     /// Rendering object.
     ///
     /// If Spin is specified, ThrobberState.index is used.
+    ///
+    /// If ThrobberState has an outcome, it takes precedence over this.
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[derive(Debug, Clone, PartialEq, Eq)]
     pub enum WhichUse {
         Full,
         Empty,
         Spin,
+        Outcome(Outcome),
+        /// A block bouncing back and forth across the width.
+        ///
+        /// ThrobberState.index is the position in sub-cells, and a cell has symbols.len() sub-cells.
+        /// Symbols are used as the partially filled cells at the edges (e.g. HORIZONTAL_BLOCK).
+        /// In Span and Line conversions, which have no width, it spins like Spin.
+        Bounce,
+        /// A block sweeping across the width from left to right repeatedly.
+        ///
+        /// Same as Bounce except for the direction.
+        Sweep,
+    }
+
+    /// Final result of the task indicated by throbber.
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub enum Outcome {
+        Success,
+        Failure,
+        Warning,
+        Skipped,
+        Cancelled,
+    }
+
+    /// A symbol and its style to be rendered for an Outcome.
+    ///
+    /// The style is patched over the throbber_style of Throbber.
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct OutcomeSymbol {
+        pub symbol: Cow<'static, str>,
+        pub style: ratatui::style::Style,
+    }
+
+    /// A set of symbols to be rendered when the task is finished.
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct OutcomeSet {
+        pub success: OutcomeSymbol,
+        pub failure: OutcomeSymbol,
+        pub warning: OutcomeSymbol,
+        pub skipped: OutcomeSymbol,
+        pub cancelled: OutcomeSymbol,
+    }
+
+    impl OutcomeSet {
+        /// Get the symbol for the outcome.
+        ///
+        /// # Examples:
+        /// ```
+        /// use throbber_widgets_tui::{Outcome, OUTCOME_UNICODE};
+        /// assert_eq!(OUTCOME_UNICODE.get(Outcome::Success).symbol, "✔");
+        /// ```
+        pub fn get(&self, outcome: Outcome) -> &OutcomeSymbol {
+            match outcome {
+                Outcome::Success => &self.success,
+                Outcome::Failure => &self.failure,
+                Outcome::Warning => &self.warning,
+                Outcome::Skipped => &self.skipped,
+                Outcome::Cancelled => &self.cancelled,
+            }
+        }
     }
 
+    const SUCCESS_STYLE: ratatui::style::Style =
+        ratatui::style::Style::new().fg(ratatui::style::Color::Green);
+    const FAILURE_STYLE: ratatui::style::Style =
+        ratatui::style::Style::new().fg(ratatui::style::Color::Red);
+    const WARNING_STYLE: ratatui::style::Style =
+        ratatui::style::Style::new().fg(ratatui::style::Color::Yellow);
+    const SKIPPED_STYLE: ratatui::style::Style =
+        ratatui::style::Style::new().fg(ratatui::style::Color::DarkGray);
+    const CANCELLED_STYLE: ratatui::style::Style =
+        ratatui::style::Style::new().fg(ratatui::style::Color::DarkGray);
+
+    /// ["v", "x", "!", "-", "/"]
+    pub const OUTCOME_ASCII: OutcomeSet = OutcomeSet {
+        success: OutcomeSymbol {
+            symbol: Cow::Borrowed("v"),
+            style: SUCCESS_STYLE,
+        },
+        failure: OutcomeSymbol {
+            symbol: Cow::Borrowed("x"),
+            style: FAILURE_STYLE,
+        },
+        warning: OutcomeSymbol {
+            symbol: Cow::Borrowed("!"),
+            style: WARNING_STYLE,
+        },
+        skipped: OutcomeSymbol {
+            symbol: Cow::Borrowed("-"),
+            style: SKIPPED_STYLE,
+        },
+        cancelled: OutcomeSymbol {
+            symbol: Cow::Borrowed("/"),
+            style: CANCELLED_STYLE,
+        },
+    };
+
+    /// ["✔", "✖", "⚠", "↷", "⊘"]
+    pub const OUTCOME_UNICODE: OutcomeSet = OutcomeSet {
+        success: OutcomeSymbol {
+            symbol: Cow::Borrowed("✔"),
+            style: SUCCESS_STYLE,
+        },
+        failure: OutcomeSymbol {
+            symbol: Cow::Borrowed("✖"),
+            style: FAILURE_STYLE,
+        },
+        warning: OutcomeSymbol {
+            symbol: Cow::Borrowed("⚠"),
+            style: WARNING_STYLE,
+        },
+        skipped: OutcomeSymbol {
+            symbol: Cow::Borrowed("↷"),
+            style: SKIPPED_STYLE,
+        },
+        cancelled: OutcomeSymbol {
+            symbol: Cow::Borrowed("⊘"),
+            style: CANCELLED_STYLE,
+        },
+    };
+
+    /// ["✅", "❌", "🚧", "💤", "🚫"]
+    pub const OUTCOME_EMOJI: OutcomeSet = OutcomeSet {
+        success: OutcomeSymbol {
+            symbol: Cow::Borrowed("✅"),
+            style: SUCCESS_STYLE,
+        },
+        failure: OutcomeSymbol {
+            symbol: Cow::Borrowed("❌"),
+            style: FAILURE_STYLE,
+        },
+        warning: OutcomeSymbol {
+            symbol: Cow::Borrowed("🚧"),
+            style: WARNING_STYLE,
+        },
+        skipped: OutcomeSymbol {
+            symbol: Cow::Borrowed("💤"),
+            style: SKIPPED_STYLE,
+        },
+        cancelled: OutcomeSymbol {
+            symbol: Cow::Borrowed("🚫"),
+            style: CANCELLED_STYLE,
+        },
+    };
+
     /// ["|", "/", "-", "\\"]
     pub const ASCII: Set = Set {
-        full: "*",
-        empty: " ",
-        symbols: &["|", "/", "-", "\\"],
+        full: Cow::Borrowed("*"),
+        empty: Cow::Borrowed(" "),
+        symbols: frames!["|", "/", "-", "\\"],
+        interval: millis(130),
+        durations: Cow::Borrowed(&[]),
+        outcomes: OUTCOME_ASCII,
     };
 
     /// ["│", "╱", "─", "╲"]
     pub const BOX_DRAWING: Set = Set {
-        full: "┼",
-        empty: "　",
-        symbols: &["│", "╱", "─", "╲"],
+        full: Cow::Borrowed("┼"),
+        empty: Cow::Borrowed(" "),
+        symbols: frames!["│", "╱", "─", "╲"],
+        interval: millis(130),
+        durations: Cow::Borrowed(&[]),
+        outcomes: OUTCOME_UNICODE,
     };
 
     /// ["↑", "↗", "→", "↘", "↓", "↙", "←", "↖"]
     pub const ARROW: Set = Set {
-        full: "↔",
-        empty: "　",
-        symbols: &["↑", "↗", "→", "↘", "↓", "↙", "←", "↖"],
+        full: Cow::Borrowed("↔"),
+        empty: Cow::Borrowed(" "),
+        symbols: frames!["↑", "↗", "→", "↘", "↓", "↙", "←", "↖"],
+        interval: millis(100),
+        durations: Cow::Borrowed(&[]),
+        outcomes: OUTCOME_UNICODE,
     };
 
     /// ["⇑", "⇗", "⇒", "⇘", "⇓", "⇙", "⇐", "⇖"]
     pub const DOUBLE_ARROW: Set = Set {
-        full: "⇔",
-        empty: "　",
-        symbols: &["⇑", "⇗", "⇒", "⇘", "⇓", "⇙", "⇐", "⇖"],
+        full: Cow::Borrowed("⇔"),
+        empty: Cow::Borrowed(" "),
+        symbols: frames!["⇑", "⇗", "⇒", "⇘", "⇓", "⇙", "⇐", "⇖"],
+        interval: millis(100),
+        durations: Cow::Borrowed(&[]),
+        outcomes: OUTCOME_UNICODE,
     };
 
     /// ["▁", "▂", "▃", "▄", "▅", "▆", "▇", "█"]
     pub const VERTICAL_BLOCK: Set = Set {
-        full: "█",
-        empty: "　",
-        symbols: &["▁", "▂", "▃", "▄", "▅", "▆", "▇", "█"],
+        full: Cow::Borrowed("█"),
+        empty: Cow::Borrowed(" "),
+        symbols: frames!["▁", "▂", "▃", "▄", "▅", "▆", "▇", "█"],
+        interval: millis(80),
+        durations: Cow::Borrowed(&[
+            millis(80),
+            millis(80),
+            millis(80),
+            millis(80),
+            millis(80),
+            millis(80),
+            millis(80),
+            millis(400),
+        ]),
+        outcomes: OUTCOME_UNICODE,
     };
 
     /// ["▏", "▎", "▍", "▌", "▋", "▊", "▉", "█"]
     pub const HORIZONTAL_BLOCK: Set = Set {
-        full: "█",
-        empty: "　",
-        symbols: &["▏", "▎", "▍", "▌", "▋", "▊", "▉", "█"],
+        full: Cow::Borrowed("█"),
+        empty: Cow::Borrowed(" "),
+        symbols: frames!["▏", "▎", "▍", "▌", "▋", "▊", "▉", "█"],
+        interval: millis(80),
+        durations: Cow::Borrowed(&[
+            millis(80),
+            millis(80),
+            millis(80),
+            millis(80),
+            millis(80),
+            millis(80),
+            millis(80),
+            millis(400),
+        ]),
+        outcomes: OUTCOME_UNICODE,
     };
 
     /// ["▝", "▗", "▖", "▘"]
     pub const QUADRANT_BLOCK: Set = Set {
-        full: "█",
-        empty: "　",
-        symbols: &["▝", "▗", "▖", "▘"],
+        full: Cow::Borrowed("█"),
+        empty: Cow::Borrowed(" "),
+        symbols: frames!["▝", "▗", "▖", "▘"],
+        interval: millis(120),
+        durations: Cow::Borrowed(&[]),
+        outcomes: OUTCOME_UNICODE,
     };
 
     /// ["▙", "▛", "▜", "▟"]
     pub const QUADRANT_BLOCK_CRACK: Set = Set {
-        full: "█",
-        empty: "　",
-        symbols: &["▙", "▛", "▜", "▟"],
+        full: Cow::Borrowed("█"),
+        empty: Cow::Borrowed(" "),
+        symbols: frames!["▙", "▛", "▜", "▟"],
+        interval: millis(120),
+        durations: Cow::Borrowed(&[]),
+        outcomes: OUTCOME_UNICODE,
     };
 
     /// ["◳", "◲", "◱", "◰"]
     pub const WHITE_SQUARE: Set = Set {
-        full: "⊞",
-        empty: "　",
-        symbols: &["◳", "◲", "◱", "◰"],
+        full: Cow::Borrowed("⊞"),
+        empty: Cow::Borrowed(" "),
+        symbols: frames!["◳", "◲", "◱", "◰"],
+        interval: millis(120),
+        durations: Cow::Borrowed(&[]),
+        outcomes: OUTCOME_UNICODE,
     };
 
     /// ["◷", "◶", "◵", "◴"]
     pub const WHITE_CIRCLE: Set = Set {
-        full: "⊕",
-        empty: "　",
-        symbols: &["◷", "◶", "◵", "◴"],
+        full: Cow::Borrowed("⊕"),
+        empty: Cow::Borrowed(" "),
+        symbols: frames!["◷", "◶", "◵", "◴"],
+        interval: millis(120),
+        durations: Cow::Borrowed(&[]),
+        outcomes: OUTCOME_UNICODE,
     };
 
     /// ["◑", "◒", "◐", "◓"]
     pub const BLACK_CIRCLE: Set = Set {
-        full: "●",
-        empty: "　",
-        symbols: &["◑", "◒", "◐", "◓"],
+        full: Cow::Borrowed("●"),
+        empty: Cow::Borrowed(" "),
+        symbols: frames!["◑", "◒", "◐", "◓"],
+        interval: millis(120),
+        durations: Cow::Borrowed(&[]),
+        outcomes: OUTCOME_UNICODE,
     };
 
     /// ["🕛", "🕧", "🕐", "🕜", "🕑", ..., "🕚", "🕦"]
     pub const CLOCK: Set = Set {
-        full: "🕛",
-        empty: "　",
-        symbols: &[
+        full: Cow::Borrowed("🕛"),
+        empty: Cow::Borrowed("　"),
+        symbols: frames![
             "🕛", "🕧", "🕐", "🕜", "🕑", "🕝", "🕒", "🕞", "🕓", "🕟", "🕔", "🕠", "🕕", "🕡",
             "🕖", "🕢", "🕗", "🕣", "🕘", "🕤", "🕙", "🕥", "🕚", "🕦",
         ],
+        interval: millis(50),
+        durations: Cow::Borrowed(&[]),
+        outcomes: OUTCOME_EMOJI,
     };
 
     /// ["⠈", "⠐", "⠠", "⠄", "⠂", "⠁"]
     pub const BRAILLE_ONE: Set = Set {
-        full: "⠿",
-        empty: "　",
-        symbols: &["⠈", "⠐", "⠠", "⠄", "⠂", "⠁"],
+        full: Cow::Borrowed("⠿"),
+        empty: Cow::Borrowed(" "),
+        symbols: frames!["⠈", "⠐", "⠠", "⠄", "⠂", "⠁"],
+        interval: millis(80),
+        durations: Cow::Borrowed(&[]),
+        outcomes: OUTCOME_UNICODE,
     };
 
     /// ["⠘", "⠰", "⠤", "⠆", "⠃", "⠉"]
     pub const BRAILLE_DOUBLE: Set = Set {
-        full: "⠿",
-        empty: "　",
-        symbols: &["⠘", "⠰", "⠤", "⠆", "⠃", "⠉"],
+        full: Cow::Borrowed("⠿"),
+        empty: Cow::Borrowed(" "),
+        symbols: frames!["⠘", "⠰", "⠤", "⠆", "⠃", "⠉"],
+        interval: millis(80),
+        durations: Cow::Borrowed(&[]),
+        outcomes: OUTCOME_UNICODE,
     };
 
     /// ["⠷", "⠯", "⠟", "⠻", "⠽", "⠾"]
     pub const BRAILLE_SIX: Set = Set {
-        full: "⠿",
-        empty: "　",
-        symbols: &["⠷", "⠯", "⠟", "⠻", "⠽", "⠾"],
+        full: Cow::Borrowed("⠿"),
+        empty: Cow::Borrowed(" "),
+        symbols: frames!["⠷", "⠯", "⠟", "⠻", "⠽", "⠾"],
+        interval: millis(80),
+        durations: Cow::Borrowed(&[]),
+        outcomes: OUTCOME_UNICODE,
     };
 
     /// ["⠧", "⠏", "⠛", "⠹", "⠼", "⠶"]
     pub const BRAILLE_SIX_DOUBLE: Set = Set {
-        full: "⠿",
-        empty: "　",
-        symbols: &["⠧", "⠏", "⠛", "⠹", "⠼", "⠶"],
+        full: Cow::Borrowed("⠿"),
+        empty: Cow::Borrowed(" "),
+        symbols: frames!["⠧", "⠏", "⠛", "⠹", "⠼", "⠶"],
+        interval: millis(80),
+        durations: Cow::Borrowed(&[]),
+        outcomes: OUTCOME_UNICODE,
     };
 
     /// ["⣷", "⣯", "⣟", "⡿", "⢿", "⣻", "⣽", "⣾"]
     pub const BRAILLE_EIGHT: Set = Set {
-        full: "⣿",
-        empty: "　",
-        symbols: &["⣷", "⣯", "⣟", "⡿", "⢿", "⣻", "⣽", "⣾"],
+        full: Cow::Borrowed("⣿"),
+        empty: Cow::Borrowed(" "),
+        symbols: frames!["⣷", "⣯", "⣟", "⡿", "⢿", "⣻", "⣽", "⣾"],
+        interval: millis(80),
+        durations: Cow::Borrowed(&[]),
+        outcomes: OUTCOME_UNICODE,
     };
 
     /// ["⣧", "⣏", "⡟", "⠿", "⢻", "⣹", "⣼", "⣶"]
     pub const BRAILLE_EIGHT_DOUBLE: Set = Set {
-        full: "⣿",
-        empty: "　",
-        symbols: &["⣧", "⣏", "⡟", "⠿", "⢻", "⣹", "⣼", "⣶"],
+        full: Cow::Borrowed("⣿"),
+        empty: Cow::Borrowed(" "),
+        symbols: frames!["⣧", "⣏", "⡟", "⠿", "⢻", "⣹", "⣼", "⣶"],
+        interval: millis(80),
+        durations: Cow::Borrowed(&[]),
+        outcomes: OUTCOME_UNICODE,
     };
 
     /// [" ", "ᚐ", "ᚑ", "ᚒ", "ᚓ", "ᚔ"]
     pub const OGHAM_A: Set = Set {
-        full: "ᚔ",
-        empty: "　",
-        symbols: &[" ", "ᚐ", "ᚑ", "ᚒ", "ᚓ", "ᚔ"],
+        full: Cow::Borrowed("ᚔ"),
+        empty: Cow::Borrowed(" "),
+        symbols: frames![" ", "ᚐ", "ᚑ", "ᚒ", "ᚓ", "ᚔ"],
+        interval: millis(100),
+        durations: Cow::Borrowed(&[
+            millis(200),
+            millis(100),
+            millis(100),
+            millis(100),
+            millis(100),
+            millis(400),
+        ]),
+        outcomes: OUTCOME_UNICODE,
     };
 
     /// [" ", "ᚁ", "ᚂ", "ᚃ", "ᚄ", "ᚅ"]
     pub const OGHAM_B: Set = Set {
-        full: "ᚅ",
-        empty: "　",
-        symbols: &[" ", "ᚁ", "ᚂ", "ᚃ", "ᚄ", "ᚅ"],
+        full: Cow::Borrowed("ᚅ"),
+        empty: Cow::Borrowed(" "),
+        symbols: frames![" ", "ᚁ", "ᚂ", "ᚃ", "ᚄ", "ᚅ"],
+        interval: millis(100),
+        durations: Cow::Borrowed(&[
+            millis(200),
+            millis(100),
+            millis(100),
+            millis(100),
+            millis(100),
+            millis(400),
+        ]),
+        outcomes: OUTCOME_UNICODE,
     };
 
     /// [" ", "ᚆ", "ᚇ", "ᚈ", "ᚉ", "ᚊ"]
     pub const OGHAM_C: Set = Set {
-        full: "ᚊ",
-        empty: "　",
-        symbols: &[" ", "ᚆ", "ᚇ", "ᚈ", "ᚉ", "ᚊ"],
+        full: Cow::Borrowed("ᚊ"),
+        empty: Cow::Borrowed(" "),
+        symbols: frames![" ", "ᚆ", "ᚇ", "ᚈ", "ᚉ", "ᚊ"],
+        interval: millis(100),
+        durations: Cow::Borrowed(&[
+            millis(200),
+            millis(100),
+            millis(100),
+            millis(100),
+            millis(100),
+            millis(400),
+        ]),
+        outcomes: OUTCOME_UNICODE,
     };
 
     /// ["⎛", "⎜", "⎝", "⎞", "⎟", "⎠"]
     pub const PARENTHESIS: Set = Set {
-        full: "∫",
-        empty: "　",
-        symbols: &["⎛", "⎜", "⎝", "⎞", "⎟", "⎠"],
+        full: Cow::Borrowed("∫"),
+        empty: Cow::Borrowed(" "),
+        symbols: frames!["⎛", "⎜", "⎝", "⎞", "⎟", "⎠"],
+        interval: millis(100),
+        durations: Cow::Borrowed(&[]),
+        outcomes: OUTCOME_UNICODE,
     };
 
     /// ["ᔐ", "ᯇ", "ᔑ", "ᯇ"]
     pub const CANADIAN: Set = Set {
-        full: "ᦟ",
-        empty: "　",
-        symbols: &["ᔐ", "ᯇ", "ᔑ", "ᯇ"],
+        full: Cow::Borrowed("ᦟ"),
+        empty: Cow::Borrowed(" "),
+        symbols: frames!["ᔐ", "ᯇ", "ᔑ", "ᯇ"],
+        interval: millis(150),
+        durations: Cow::Borrowed(&[]),
+        outcomes: OUTCOME_UNICODE,
+    };
+
+    /// A ring of 6 dots (5x3 cells).
+    pub const LARGE_RING: Set = Set {
+        full: Cow::Borrowed(" ● ● \n●   ●\n ● ● "),
+        empty: Cow::Borrowed("     \n     \n     "),
+        symbols: frames![
+            " ● ○ \n○   ○\n ○ ○ ",
+            " ○ ● \n○   ○\n ○ ○ ",
+            " ○ ○ \n○   ●\n ○ ○ ",
+            " ○ ○ \n○   ○\n ○ ● ",
+            " ○ ○ \n○   ○\n ● ○ ",
+            " ○ ○ \n●   ○\n ○ ○ ",
+        ],
+        interval: millis(100),
+        durations: Cow::Borrowed(&[]),
+        outcomes: OUTCOME_UNICODE,
+    };
+
+    /// A block bouncing in a box (8x3 cells).
+    pub const LARGE_BOUNCE: Set = Set {
+        full: Cow::Borrowed("┌──────┐\n│██████│\n└──────┘"),
+        empty: Cow::Borrowed("        \n        \n        "),
+        symbols: frames![
+            "┌──────┐\n│██    │\n└──────┘",
+            "┌──────┐\n│ ██   │\n└──────┘",
+            "┌──────┐\n│  ██  │\n└──────┘",
+            "┌──────┐\n│   ██ │\n└──────┘",
+            "┌──────┐\n│    ██│\n└──────┘",
+            "┌──────┐\n│   ██ │\n└──────┘",
+            "┌──────┐\n│  ██  │\n└──────┘",
+            "┌──────┐\n│ ██   │\n└──────┘",
+        ],
+        interval: millis(100),
+        durations: Cow::Borrowed(&[]),
+        outcomes: OUTCOME_UNICODE,
+    };
+
+    /// A clock hand going around (7x5 cells).
+    pub const LARGE_CLOCK: Set = Set {
+        full: Cow::Borrowed("╭─────╮\n│     │\n│  ●  │\n│     │\n╰─────╯"),
+        empty: Cow::Borrowed("       \n       \n       \n       \n       "),
+        symbols: frames![
+            "╭─────╮\n│  │  │\n│  ●  │\n│     │\n╰─────╯",
+            "╭─────╮\n│   ╱ │\n│  ●  │\n│     │\n╰─────╯",
+            "╭─────╮\n│     │\n│  ●─ │\n│     │\n╰─────╯",
+            "╭─────╮\n│     │\n│  ●  │\n│   ╲ │\n╰─────╯",
+            "╭─────╮\n│     │\n│  ●  │\n│  │  │\n╰─────╯",
+            "╭─────╮\n│     │\n│  ●  │\n│ ╱   │\n╰─────╯",
+            "╭─────╮\n│     │\n│ ─●  │\n│     │\n╰─────╯",
+            "╭─────╮\n│ ╲   │\n│  ●  │\n│     │\n╰─────╯",
+        ],
+        interval: millis(125),
+        durations: Cow::Borrowed(&[]),
+        outcomes: OUTCOME_UNICODE,
     };
+
+    /// All built-in sets with their names.
+    ///
+    /// # Examples:
+    /// ```
+    /// for (name, set) in throbber_widgets_tui::BUILTIN_SETS {
+    ///     println!("{name}: {}", set.symbols.join(""));
+    /// }
+    /// ```
+    pub static BUILTIN_SETS: &[(&str, Set)] = &[
+        ("ASCII", ASCII),
+        ("BOX_DRAWING", BOX_DRAWING),
+        ("ARROW", ARROW),
+        ("DOUBLE_ARROW", DOUBLE_ARROW),
+        ("VERTICAL_BLOCK", VERTICAL_BLOCK),
+        ("HORIZONTAL_BLOCK", HORIZONTAL_BLOCK),
+        ("QUADRANT_BLOCK", QUADRANT_BLOCK),
+        ("QUADRANT_BLOCK_CRACK", QUADRANT_BLOCK_CRACK),
+        ("WHITE_SQUARE", WHITE_SQUARE),
+        ("WHITE_CIRCLE", WHITE_CIRCLE),
+        ("BLACK_CIRCLE", BLACK_CIRCLE),
+        ("CLOCK", CLOCK),
+        ("BRAILLE_ONE", BRAILLE_ONE),
+        ("BRAILLE_DOUBLE", BRAILLE_DOUBLE),
+        ("BRAILLE_SIX", BRAILLE_SIX),
+        ("BRAILLE_SIX_DOUBLE", BRAILLE_SIX_DOUBLE),
+        ("BRAILLE_EIGHT", BRAILLE_EIGHT),
+        ("BRAILLE_EIGHT_DOUBLE", BRAILLE_EIGHT_DOUBLE),
+        ("OGHAM_A", OGHAM_A),
+        ("OGHAM_B", OGHAM_B),
+        ("OGHAM_C", OGHAM_C),
+        ("PARENTHESIS", PARENTHESIS),
+        ("CANADIAN", CANADIAN),
+    ];
+
+    /// Built-in sets of multi-line symbols with their names.
+    ///
+    /// Each symbol is lines joined by `\n`. Throbber renders them at the center of the area
+    /// with the label below.
+    pub static LARGE_SETS: &[(&str, Set)] = &[
+        ("LARGE_RING", LARGE_RING),
+        ("LARGE_BOUNCE", LARGE_BOUNCE),
+        ("LARGE_CLOCK", LARGE_CLOCK),
+    ];
+
+    /// Iterate BUILTIN_SETS, LARGE_SETS and CLI_SPINNERS with the `cli-spinners` feature.
+    ///
+    /// The built-in sets take precedence, so the spinners of cli-spinners whose names collide
+    /// with them (`arrow` and `clock`) are skipped. They are still available in the module.
+    fn named_sets() -> impl Iterator<Item = &'static (&'static str, Set)> {
+        let sets = BUILTIN_SETS.iter().chain(LARGE_SETS);
+        #[cfg(feature = "cli-spinners")]
+        let sets = sets.chain(
+            super::cli_spinners::CLI_SPINNERS
+                .iter()
+                .filter(|(name, _)| {
+                    !BUILTIN_SETS
+                        .iter()
+                        .chain(LARGE_SETS)
+                        .any(|(builtin, _)| builtin.eq_ignore_ascii_case(name))
+                }),
+        );
+        sets
+    }
+
+    /// Look up a set of BUILTIN_SETS or LARGE_SETS by its name, ignoring ASCII case.
+    ///
+    /// With the `cli-spinners` feature, the spinners of cli-spinners are also looked up, e.g. `bouncingBar`.
+    ///
+    /// # Examples:
+    /// ```
+    /// let set = throbber_widgets_tui::builtin("braille_eight");
+    /// assert_eq!(set, Some(&throbber_widgets_tui::BRAILLE_EIGHT));
+    /// assert_eq!(throbber_widgets_tui::builtin("no_such_set"), None);
+    /// ```
+    pub fn builtin(name: &str) -> Option<&'static Set> {
+        named_sets()
+            .find(|(builtin, _)| builtin.eq_ignore_ascii_case(name))
+            .map(|(_, set)| set)
+    }
+
+    /// Named sets to be looked up at runtime, e.g. by a command line flag.
+    ///
+    /// The default registry contains BUILTIN_SETS and LARGE_SETS (and the spinners of cli-spinners with the feature),
+    /// and applications can register their own sets.
+    /// Names are compared ignoring ASCII case.
+    ///
+    /// # Examples:
+    /// ```
+    /// use throbber_widgets_tui::{Set, SetRegistry};
+    /// let mut registry = SetRegistry::default();
+    /// registry.register("moon", Set::new("🌕", "🌑", ["🌑", "🌒", "🌓", "🌔", "🌕"]));
+    /// assert_eq!(registry.get("Moon").unwrap().symbols.len(), 5);
+    /// assert_eq!(registry.get("BRAILLE_EIGHT"), Some(&throbber_widgets_tui::BRAILLE_EIGHT));
+    /// assert!(registry.names().any(|name| name == "moon"));
+    /// ```
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct SetRegistry {
+        sets: alloc::vec::Vec<(Cow<'static, str>, Set)>,
+    }
+
+    impl Default for SetRegistry {
+        fn default() -> Self {
+            Self {
+                sets: named_sets()
+                    .map(|(name, set)| (Cow::Borrowed(*name), set.clone()))
+                    .collect(),
+            }
+        }
+    }
+
+    impl SetRegistry {
+        /// Create a registry without any sets.
+        pub fn empty() -> Self {
+            Self {
+                sets: alloc::vec::Vec::new(),
+            }
+        }
+
+        /// Register the set with the name.
+        ///
+        /// If the name is already registered, the set is replaced and the old one is returned.
+        pub fn register<T>(&mut self, name: T, set: Set) -> Option<Set>
+        where
+            T: Into<Cow<'static, str>>,
+        {
+            let name = name.into();
+            match self.get_mut(&name) {
+                Some(registered) => Some(core::mem::replace(registered, set)),
+                None => {
+                    self.sets.push((name, set));
+                    None
+                }
+            }
+        }
+
+        /// Remove the set with the name.
+        pub fn unregister(&mut self, name: &str) -> Option<Set> {
+            let index = self
+                .sets
+                .iter()
+                .position(|(registered, _)| registered.eq_ignore_ascii_case(name))?;
+            Some(self.sets.remove(index).1)
+        }
+
+        /// Look up a set by its name, ignoring ASCII case.
+        pub fn get(&self, name: &str) -> Option<&Set> {
+            self.sets
+                .iter()
+                .find(|(registered, _)| registered.eq_ignore_ascii_case(name))
+                .map(|(_, set)| set)
+        }
+
+        fn get_mut(&mut self, name: &str) -> Option<&mut Set> {
+            self.sets
+                .iter_mut()
+                .find(|(registered, _)| registered.eq_ignore_ascii_case(name))
+                .map(|(_, set)| set)
+        }
+
+        /// Iterate the names and the sets in the registered order.
+        pub fn iter(&self) -> impl Iterator<Item = (&str, &Set)> {
+            self.sets.iter().map(|(name, set)| (&**name, set))
+        }
+
+        /// Iterate the names in the registered order.
+        pub fn names(&self) -> impl Iterator<Item = &str> {
+            self.iter().map(|(name, _)| name)
+        }
+
+        pub fn len(&self) -> usize {
+            self.sets.len()
+        }
+
+        pub fn is_empty(&self) -> bool {
+            self.sets.is_empty()
+        }
+    }
 }
+
+#[cfg(feature = "cli-spinners")]
+pub mod cli_spinners;
@@ -0,0 +1,1256 @@
+//! Spinners imported from [cli-spinners](https://github.com/sindresorhus/cli-spinners).
+//!
+//! Enabled by the `cli-spinners` feature.
+//! They are not re-exported at the crate root because some names (e.g. ARROW) collide with the built-in sets.
+//! By their names in cli-spinners, they are looked up by `builtin()`, serde and the default `SetRegistry`,
+//! except `arrow` and `clock`, which resolve to the built-in sets.
+//!
+//! Trailing spaces of the original frames are removed, and every frame is padded to the same display width.
+//! `full` is a filled glyph of the same family (e.g. `⠿` for dots or the filled bar for material),
+//! or the first frame if the spinner has no such state. `empty` is spaces of that width.
+//!
+//! The original data is released under the MIT License:
+//!
+//! > Copyright (c) Sindre Sorhus <sindresorhus@gmail.com> (<https://sindresorhus.com>)
+//! >
+//! > Permission is hereby granted, free of charge, to any person obtaining a copy of this software and associated documentation files (the "Software"), to deal in the Software without restriction, including without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of the Software, and to permit persons to whom the Software is furnished to do so, subject to the following conditions:
+//! >
+//! > The above copyright notice and this permission notice shall be included in all copies or substantial portions of the Software.
+//! >
+//! > THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+use alloc::borrow::Cow;
+
+use super::throbber::{Set, OUTCOME_UNICODE};
+
+const fn millis(ms: u64) -> core::time::Duration {
+    core::time::Duration::from_millis(ms)
+}
+
+/// All spinners of this module with their names in cli-spinners.
+///
+/// # Examples:
+/// ```
+/// use throbber_widgets_tui::symbols::cli_spinners::CLI_SPINNERS;
+/// let mut registry = throbber_widgets_tui::SetRegistry::empty();
+/// for (name, set) in CLI_SPINNERS {
+///     registry.register(*name, set.clone());
+/// }
+/// assert_eq!(registry.get("bouncingBar").unwrap().symbols[1], "[=   ]");
+/// ```
+pub static CLI_SPINNERS: &[(&str, Set)] = &[
+    ("dots", DOTS),
+    ("dots2", DOTS2),
+    ("dots3", DOTS3),
+    ("dots4", DOTS4),
+    ("dots5", DOTS5),
+    ("dots6", DOTS6),
+    ("dots7", DOTS7),
+    ("dots8", DOTS8),
+    ("dots9", DOTS9),
+    ("dots10", DOTS10),
+    ("dots11", DOTS11),
+    ("dots12", DOTS12),
+    ("dots8Bit", DOTS8_BIT),
+    ("line", LINE),
+    ("line2", LINE2),
+    ("pipe", PIPE),
+    ("simpleDots", SIMPLE_DOTS),
+    ("simpleDotsScrolling", SIMPLE_DOTS_SCROLLING),
+    ("star", STAR),
+    ("star2", STAR2),
+    ("flip", FLIP),
+    ("hamburger", HAMBURGER),
+    ("growVertical", GROW_VERTICAL),
+    ("growHorizontal", GROW_HORIZONTAL),
+    ("balloon", BALLOON),
+    ("balloon2", BALLOON2),
+    ("noise", NOISE),
+    ("bounce", BOUNCE),
+    ("boxBounce", BOX_BOUNCE),
+    ("boxBounce2", BOX_BOUNCE2),
+    ("triangle", TRIANGLE),
+    ("arc", ARC),
+    ("circle", CIRCLE),
+    ("squareCorners", SQUARE_CORNERS),
+    ("circleQuarters", CIRCLE_QUARTERS),
+    ("circleHalves", CIRCLE_HALVES),
+    ("squish", SQUISH),
+    ("toggle", TOGGLE),
+    ("toggle2", TOGGLE2),
+    ("toggle3", TOGGLE3),
+    ("toggle4", TOGGLE4),
+    ("toggle5", TOGGLE5),
+    ("toggle6", TOGGLE6),
+    ("toggle7", TOGGLE7),
+    ("toggle8", TOGGLE8),
+    ("toggle9", TOGGLE9),
+    ("toggle10", TOGGLE10),
+    ("toggle11", TOGGLE11),
+    ("toggle12", TOGGLE12),
+    ("toggle13", TOGGLE13),
+    ("arrow", ARROW),
+    ("arrow2", ARROW2),
+    ("arrow3", ARROW3),
+    ("bouncingBar", BOUNCING_BAR),
+    ("bouncingBall", BOUNCING_BALL),
+    ("smiley", SMILEY),
+    ("monkey", MONKEY),
+    ("hearts", HEARTS),
+    ("clock", CLOCK),
+    ("earth", EARTH),
+    ("material", MATERIAL),
+    ("moon", MOON),
+    ("runner", RUNNER),
+    ("pong", PONG),
+    ("shark", SHARK),
+    ("dqpb", DQPB),
+    ("weather", WEATHER),
+    ("christmas", CHRISTMAS),
+    ("grenade", GRENADE),
+    ("point", POINT),
+    ("layer", LAYER),
+    ("betaWave", BETA_WAVE),
+    ("fingerDance", FINGER_DANCE),
+    ("fistBump", FIST_BUMP),
+    ("soccerHeader", SOCCER_HEADER),
+    ("mindblown", MINDBLOWN),
+    ("speaker", SPEAKER),
+    ("orangePulse", ORANGE_PULSE),
+    ("bluePulse", BLUE_PULSE),
+    ("orangeBluePulse", ORANGE_BLUE_PULSE),
+    ("timeTravel", TIME_TRAVEL),
+    ("aesthetic", AESTHETIC),
+];
+
+/// `dots`: `["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", ...]`
+pub const DOTS: Set = Set {
+    full: Cow::Borrowed("⠿"),
+    empty: Cow::Borrowed(" "),
+    symbols: frames!["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"],
+    interval: millis(80),
+    durations: Cow::Borrowed(&[]),
+    outcomes: OUTCOME_UNICODE,
+};
+
+/// `dots2`: `["⣾", "⣽", "⣻", "⢿", "⡿", "⣟", "⣯", "⣷"]`
+pub const DOTS2: Set = Set {
+    full: Cow::Borrowed("⣿"),
+    empty: Cow::Borrowed(" "),
+    symbols: frames!["⣾", "⣽", "⣻", "⢿", "⡿", "⣟", "⣯", "⣷"],
+    interval: millis(80),
+    durations: Cow::Borrowed(&[]),
+    outcomes: OUTCOME_UNICODE,
+};
+
+/// `dots3`: `["⠋", "⠙", "⠚", "⠞", "⠖", "⠦", ...]`
+pub const DOTS3: Set = Set {
+    full: Cow::Borrowed("⠿"),
+    empty: Cow::Borrowed(" "),
+    symbols: frames!["⠋", "⠙", "⠚", "⠞", "⠖", "⠦", "⠴", "⠲", "⠳", "⠓"],
+    interval: millis(80),
+    durations: Cow::Borrowed(&[]),
+    outcomes: OUTCOME_UNICODE,
+};
+
+/// `dots4`: `["⠄", "⠆", "⠇", "⠋", "⠙", "⠸", ...]`
+pub const DOTS4: Set = Set {
+    full: Cow::Borrowed("⠿"),
+    empty: Cow::Borrowed(" "),
+    symbols: frames![
+        "⠄", "⠆", "⠇", "⠋", "⠙", "⠸", "⠰", "⠠", "⠰", "⠸", "⠙", "⠋", "⠇", "⠆"
+    ],
+    interval: millis(80),
+    durations: Cow::Borrowed(&[]),
+    outcomes: OUTCOME_UNICODE,
+};
+
+/// `dots5`: `["⠋", "⠙", "⠚", "⠒", "⠂", "⠂", ...]`
+pub const DOTS5: Set = Set {
+    full: Cow::Borrowed("⠿"),
+    empty: Cow::Borrowed(" "),
+    symbols: frames![
+        "⠋", "⠙", "⠚", "⠒", "⠂", "⠂", "⠒", "⠲", "⠴", "⠦", "⠖", "⠒", "⠐", "⠐", "⠒", "⠓", "⠋"
+    ],
+    interval: millis(80),
+    durations: Cow::Borrowed(&[]),
+    outcomes: OUTCOME_UNICODE,
+};
+
+/// `dots6`: `["⠁", "⠉", "⠙", "⠚", "⠒", "⠂", ...]`
+pub const DOTS6: Set = Set {
+    full: Cow::Borrowed("⠿"),
+    empty: Cow::Borrowed(" "),
+    symbols: frames![
+        "⠁", "⠉", "⠙", "⠚", "⠒", "⠂", "⠂", "⠒", "⠲", "⠴", "⠤", "⠄", "⠄", "⠤", "⠴", "⠲", "⠒", "⠂",
+        "⠂", "⠒", "⠚", "⠙", "⠉", "⠁"
+    ],
+    interval: millis(80),
+    durations: Cow::Borrowed(&[]),
+    outcomes: OUTCOME_UNICODE,
+};
+
+/// `dots7`: `["⠈", "⠉", "⠋", "⠓", "⠒", "⠐", ...]`
+pub const DOTS7: Set = Set {
+    full: Cow::Borrowed("⠿"),
+    empty: Cow::Borrowed(" "),
+    symbols: frames![
+        "⠈", "⠉", "⠋", "⠓", "⠒", "⠐", "⠐", "⠒", "⠖", "⠦", "⠤", "⠠", "⠠", "⠤", "⠦", "⠖", "⠒", "⠐",
+        "⠐", "⠒", "⠓", "⠋", "⠉", "⠈"
+    ],
+    interval: millis(80),
+    durations: Cow::Borrowed(&[]),
+    outcomes: OUTCOME_UNICODE,
+};
+
+/// `dots8`: `["⠁", "⠁", "⠉", "⠙", "⠚", "⠒", ...]`
+pub const DOTS8: Set = Set {
+    full: Cow::Borrowed("⠿"),
+    empty: Cow::Borrowed(" "),
+    symbols: frames![
+        "⠁", "⠁", "⠉", "⠙", "⠚", "⠒", "⠂", "⠂", "⠒", "⠲", "⠴", "⠤", "⠄", "⠄", "⠤", "⠠", "⠠", "⠤",
+        "⠦", "⠖", "⠒", "⠐", "⠐", "⠒", "⠓", "⠋", "⠉", "⠈", "⠈"
+    ],
+    interval: millis(80),
+    durations: Cow::Borrowed(&[]),
+    outcomes: OUTCOME_UNICODE,
+};
+
+/// `dots9`: `["⢹", "⢺", "⢼", "⣸", "⣇", "⡧", "⡗", "⡏"]`
+pub const DOTS9: Set = Set {
+    full: Cow::Borrowed("⣿"),
+    empty: Cow::Borrowed(" "),
+    symbols: frames!["⢹", "⢺", "⢼", "⣸", "⣇", "⡧", "⡗", "⡏"],
+    interval: millis(80),
+    durations: Cow::Borrowed(&[]),
+    outcomes: OUTCOME_UNICODE,
+};
+
+/// `dots10`: `["⢄", "⢂", "⢁", "⡁", "⡈", "⡐", "⡠"]`
+pub const DOTS10: Set = Set {
+    full: Cow::Borrowed("⣿"),
+    empty: Cow::Borrowed(" "),
+    symbols: frames!["⢄", "⢂", "⢁", "⡁", "⡈", "⡐", "⡠"],
+    interval: millis(80),
+    durations: Cow::Borrowed(&[]),
+    outcomes: OUTCOME_UNICODE,
+};
+
+/// `dots11`: `["⠁", "⠂", "⠄", "⡀", "⢀", "⠠", "⠐", "⠈"]`
+pub const DOTS11: Set = Set {
+    full: Cow::Borrowed("⣿"),
+    empty: Cow::Borrowed(" "),
+    symbols: frames!["⠁", "⠂", "⠄", "⡀", "⢀", "⠠", "⠐", "⠈"],
+    interval: millis(100),
+    durations: Cow::Borrowed(&[]),
+    outcomes: OUTCOME_UNICODE,
+};
+
+/// `dots12`: `["⢀⠀", "⡀⠀", "⠄⠀", "⢂⠀", "⡂⠀", "⠅⠀", ...]`
+pub const DOTS12: Set = Set {
+    full: Cow::Borrowed("⣿⣿"),
+    empty: Cow::Borrowed("  "),
+    symbols: frames![
+        "⢀⠀", "⡀⠀", "⠄⠀", "⢂⠀", "⡂⠀", "⠅⠀", "⢃⠀", "⡃⠀", "⠍⠀", "⢋⠀", "⡋⠀", "⠍⠁", "⢋⠁", "⡋⠁", "⠍⠉",
+        "⠋⠉", "⠋⠉", "⠉⠙", "⠉⠙", "⠉⠩", "⠈⢙", "⠈⡙", "⢈⠩", "⡀⢙", "⠄⡙", "⢂⠩", "⡂⢘", "⠅⡘", "⢃⠨", "⡃⢐",
+        "⠍⡐", "⢋⠠", "⡋⢀", "⠍⡁", "⢋⠁", "⡋⠁", "⠍⠉", "⠋⠉", "⠋⠉", "⠉⠙", "⠉⠙", "⠉⠩", "⠈⢙", "⠈⡙", "⠈⠩",
+        "⠀⢙", "⠀⡙", "⠀⠩", "⠀⢘", "⠀⡘", "⠀⠨", "⠀⢐", "⠀⡐", "⠀⠠", "⠀⢀", "⠀⡀"
+    ],
+    interval: millis(80),
+    durations: Cow::Borrowed(&[]),
+    outcomes: OUTCOME_UNICODE,
+};
+
+/// `dots8Bit`: `["⠀", "⠁", "⠂", "⠃", "⠄", "⠅", ...]`
+pub const DOTS8_BIT: Set = Set {
+    full: Cow::Borrowed("⣿"),
+    empty: Cow::Borrowed(" "),
+    symbols: frames![
+        "⠀", "⠁", "⠂", "⠃", "⠄", "⠅", "⠆", "⠇", "⡀", "⡁", "⡂", "⡃", "⡄", "⡅", "⡆", "⡇", "⠈", "⠉",
+        "⠊", "⠋", "⠌", "⠍", "⠎", "⠏", "⡈", "⡉", "⡊", "⡋", "⡌", "⡍", "⡎", "⡏", "⠐", "⠑", "⠒", "⠓",
+        "⠔", "⠕", "⠖", "⠗", "⡐", "⡑", "⡒", "⡓", "⡔", "⡕", "⡖", "⡗", "⠘", "⠙", "⠚", "⠛", "⠜", "⠝",
+        "⠞", "⠟", "⡘", "⡙", "⡚", "⡛", "⡜", "⡝", "⡞", "⡟", "⠠", "⠡", "⠢", "⠣", "⠤", "⠥", "⠦", "⠧",
+        "⡠", "⡡", "⡢", "⡣", "⡤", "⡥", "⡦", "⡧", "⠨", "⠩", "⠪", "⠫", "⠬", "⠭", "⠮", "⠯", "⡨", "⡩",
+        "⡪", "⡫", "⡬", "⡭", "⡮", "⡯", "⠰", "⠱", "⠲", "⠳", "⠴", "⠵", "⠶", "⠷", "⡰", "⡱", "⡲", "⡳",
+        "⡴", "⡵", "⡶", "⡷", "⠸", "⠹", "⠺", "⠻", "⠼", "⠽", "⠾", "⠿", "⡸", "⡹", "⡺", "⡻", "⡼", "⡽",
+        "⡾", "⡿", "⢀", "⢁", "⢂", "⢃", "⢄", "⢅", "⢆", "⢇", "⣀", "⣁", "⣂", "⣃", "⣄", "⣅", "⣆", "⣇",
+        "⢈", "⢉", "⢊", "⢋", "⢌", "⢍", "⢎", "⢏", "⣈", "⣉", "⣊", "⣋", "⣌", "⣍", "⣎", "⣏", "⢐", "⢑",
+        "⢒", "⢓", "⢔", "⢕", "⢖", "⢗", "⣐", "⣑", "⣒", "⣓", "⣔", "⣕", "⣖", "⣗", "⢘", "⢙", "⢚", "⢛",
+        "⢜", "⢝", "⢞", "⢟", "⣘", "⣙", "⣚", "⣛", "⣜", "⣝", "⣞", "⣟", "⢠", "⢡", "⢢", "⢣", "⢤", "⢥",
+        "⢦", "⢧", "⣠", "⣡", "⣢", "⣣", "⣤", "⣥", "⣦", "⣧", "⢨", "⢩", "⢪", "⢫", "⢬", "⢭", "⢮", "⢯",
+        "⣨", "⣩", "⣪", "⣫", "⣬", "⣭", "⣮", "⣯", "⢰", "⢱", "⢲", "⢳", "⢴", "⢵", "⢶", "⢷", "⣰", "⣱",
+        "⣲", "⣳", "⣴", "⣵", "⣶", "⣷", "⢸", "⢹", "⢺", "⢻", "⢼", "⢽", "⢾", "⢿", "⣸", "⣹", "⣺", "⣻",
+        "⣼", "⣽", "⣾", "⣿"
+    ],
+    interval: millis(80),
+    durations: Cow::Borrowed(&[]),
+    outcomes: OUTCOME_UNICODE,
+};
+
+/// `line`: `["-", "\\", "|", "/"]`
+pub const LINE: Set = Set {
+    full: Cow::Borrowed("-"),
+    empty: Cow::Borrowed(" "),
+    symbols: frames!["-", "\\", "|", "/"],
+    interval: millis(130),
+    durations: Cow::Borrowed(&[]),
+    outcomes: OUTCOME_UNICODE,
+};
+
+/// `line2`: `["⠂", "-", "–", "—", "–", "-"]`
+pub const LINE2: Set = Set {
+    full: Cow::Borrowed("—"),
+    empty: Cow::Borrowed(" "),
+    symbols: frames!["⠂", "-", "–", "—", "–", "-"],
+    interval: millis(100),
+    durations: Cow::Borrowed(&[]),
+    outcomes: OUTCOME_UNICODE,
+};
+
+/// `pipe`: `["┤", "┘", "┴", "└", "├", "┌", "┬", "┐"]`
+pub const PIPE: Set = Set {
+    full: Cow::Borrowed("┼"),
+    empty: Cow::Borrowed(" "),
+    symbols: frames!["┤", "┘", "┴", "└", "├", "┌", "┬", "┐"],
+    interval: millis(100),
+    durations: Cow::Borrowed(&[]),
+    outcomes: OUTCOME_UNICODE,
+};
+
+/// `simpleDots`: `[".  ", ".. ", "...", "   "]`
+pub const SIMPLE_DOTS: Set = Set {
+    full: Cow::Borrowed("..."),
+    empty: Cow::Borrowed("   "),
+    symbols: frames![".  ", ".. ", "...", "   "],
+    interval: millis(400),
+    durations: Cow::Borrowed(&[]),
+    outcomes: OUTCOME_UNICODE,
+};
+
+/// `simpleDotsScrolling`: `[".  ", ".. ", "...", " ..", "  .", "   "]`
+pub const SIMPLE_DOTS_SCROLLING: Set = Set {
+    full: Cow::Borrowed("..."),
+    empty: Cow::Borrowed("   "),
+    symbols: frames![".  ", ".. ", "...", " ..", "  .", "   "],
+    interval: millis(200),
+    durations: Cow::Borrowed(&[]),
+    outcomes: OUTCOME_UNICODE,
+};
+
+/// `star`: `["✶", "✸", "✹", "✺", "✹", "✷"]`
+pub const STAR: Set = Set {
+    full: Cow::Borrowed("✺"),
+    empty: Cow::Borrowed(" "),
+    symbols: frames!["✶", "✸", "✹", "✺", "✹", "✷"],
+    interval: millis(70),
+    durations: Cow::Borrowed(&[]),
+    outcomes: OUTCOME_UNICODE,
+};
+
+/// `star2`: `["+", "x", "*"]`
+pub const STAR2: Set = Set {
+    full: Cow::Borrowed("*"),
+    empty: Cow::Borrowed(" "),
+    symbols: frames!["+", "x", "*"],
+    interval: millis(80),
+    durations: Cow::Borrowed(&[]),
+    outcomes: OUTCOME_UNICODE,
+};
+
+/// `flip`: ["_", "_", "_", "-", "`", "`", ...]
+pub const FLIP: Set = Set {
+    full: Cow::Borrowed("_"),
+    empty: Cow::Borrowed(" "),
+    symbols: frames!["_", "_", "_", "-", "`", "`", "'", "´", "-", "_", "_", "_"],
+    interval: millis(70),
+    durations: Cow::Borrowed(&[]),
+    outcomes: OUTCOME_UNICODE,
+};
+
+/// `hamburger`: `["☱", "☲", "☴"]`
+pub const HAMBURGER: Set = Set {
+    full: Cow::Borrowed("☰"),
+    empty: Cow::Borrowed("  "),
+    symbols: frames!["☱", "☲", "☴"],
+    interval: millis(100),
+    durations: Cow::Borrowed(&[]),
+    outcomes: OUTCOME_UNICODE,
+};
+
+/// `growVertical`: `["▁", "▃", "▄", "▅", "▆", "▇", ...]`
+pub const GROW_VERTICAL: Set = Set {
+    full: Cow::Borrowed("█"),
+    empty: Cow::Borrowed(" "),
+    symbols: frames!["▁", "▃", "▄", "▅", "▆", "▇", "▆", "▅", "▄", "▃"],
+    interval: millis(120),
+    durations: Cow::Borrowed(&[]),
+    outcomes: OUTCOME_UNICODE,
+};
+
+/// `growHorizontal`: `["▏", "▎", "▍", "▌", "▋", "▊", ...]`
+pub const GROW_HORIZONTAL: Set = Set {
+    full: Cow::Borrowed("█"),
+    empty: Cow::Borrowed(" "),
+    symbols: frames!["▏", "▎", "▍", "▌", "▋", "▊", "▉", "▊", "▋", "▌", "▍", "▎"],
+    interval: millis(120),
+    durations: Cow::Borrowed(&[]),
+    outcomes: OUTCOME_UNICODE,
+};
+
+/// `balloon`: `[" ", ".", "o", "O", "@", "*", " "]`
+pub const BALLOON: Set = Set {
+    full: Cow::Borrowed("O"),
+    empty: Cow::Borrowed(" "),
+    symbols: frames![" ", ".", "o", "O", "@", "*", " "],
+    interval: millis(140),
+    durations: Cow::Borrowed(&[]),
+    outcomes: OUTCOME_UNICODE,
+};
+
+/// `balloon2`: `[".", "o", "O", "°", "O", "o", "."]`
+pub const BALLOON2: Set = Set {
+    full: Cow::Borrowed("O"),
+    empty: Cow::Borrowed(" "),
+    symbols: frames![".", "o", "O", "°", "O", "o", "."],
+    interval: millis(120),
+    durations: Cow::Borrowed(&[]),
+    outcomes: OUTCOME_UNICODE,
+};
+
+/// `noise`: `["▓", "▒", "░"]`
+pub const NOISE: Set = Set {
+    full: Cow::Borrowed("▓"),
+    empty: Cow::Borrowed(" "),
+    symbols: frames!["▓", "▒", "░"],
+    interval: millis(100),
+    durations: Cow::Borrowed(&[]),
+    outcomes: OUTCOME_UNICODE,
+};
+
+/// `bounce`: `["⠁", "⠂", "⠄", "⠂"]`
+pub const BOUNCE: Set = Set {
+    full: Cow::Borrowed("⠇"),
+    empty: Cow::Borrowed(" "),
+    symbols: frames!["⠁", "⠂", "⠄", "⠂"],
+    interval: millis(120),
+    durations: Cow::Borrowed(&[]),
+    outcomes: OUTCOME_UNICODE,
+};
+
+/// `boxBounce`: `["▖", "▘", "▝", "▗"]`
+pub const BOX_BOUNCE: Set = Set {
+    full: Cow::Borrowed("█"),
+    empty: Cow::Borrowed(" "),
+    symbols: frames!["▖", "▘", "▝", "▗"],
+    interval: millis(120),
+    durations: Cow::Borrowed(&[]),
+    outcomes: OUTCOME_UNICODE,
+};
+
+/// `boxBounce2`: `["▌", "▀", "▐", "▄"]`
+pub const BOX_BOUNCE2: Set = Set {
+    full: Cow::Borrowed("█"),
+    empty: Cow::Borrowed(" "),
+    symbols: frames!["▌", "▀", "▐", "▄"],
+    interval: millis(100),
+    durations: Cow::Borrowed(&[]),
+    outcomes: OUTCOME_UNICODE,
+};
+
+/// `triangle`: `["◢", "◣", "◤", "◥"]`
+pub const TRIANGLE: Set = Set {
+    full: Cow::Borrowed("■"),
+    empty: Cow::Borrowed(" "),
+    symbols: frames!["◢", "◣", "◤", "◥"],
+    interval: millis(50),
+    durations: Cow::Borrowed(&[]),
+    outcomes: OUTCOME_UNICODE,
+};
+
+/// `arc`: `["◜", "◠", "◝", "◞", "◡", "◟"]`
+pub const ARC: Set = Set {
+    full: Cow::Borrowed("○"),
+    empty: Cow::Borrowed(" "),
+    symbols: frames!["◜", "◠", "◝", "◞", "◡", "◟"],
+    interval: millis(100),
+    durations: Cow::Borrowed(&[]),
+    outcomes: OUTCOME_UNICODE,
+};
+
+/// `circle`: `["◡", "⊙", "◠"]`
+pub const CIRCLE: Set = Set {
+    full: Cow::Borrowed("⊙"),
+    empty: Cow::Borrowed(" "),
+    symbols: frames!["◡", "⊙", "◠"],
+    interval: millis(120),
+    durations: Cow::Borrowed(&[]),
+    outcomes: OUTCOME_UNICODE,
+};
+
+/// `squareCorners`: `["◰", "◳", "◲", "◱"]`
+pub const SQUARE_CORNERS: Set = Set {
+    full: Cow::Borrowed("■"),
+    empty: Cow::Borrowed(" "),
+    symbols: frames!["◰", "◳", "◲", "◱"],
+    interval: millis(180),
+    durations: Cow::Borrowed(&[]),
+    outcomes: OUTCOME_UNICODE,
+};
+
+/// `circleQuarters`: `["◴", "◷", "◶", "◵"]`
+pub const CIRCLE_QUARTERS: Set = Set {
+    full: Cow::Borrowed("●"),
+    empty: Cow::Borrowed(" "),
+    symbols: frames!["◴", "◷", "◶", "◵"],
+    interval: millis(120),
+    durations: Cow::Borrowed(&[]),
+    outcomes: OUTCOME_UNICODE,
+};
+
+/// `circleHalves`: `["◐", "◓", "◑", "◒"]`
+pub const CIRCLE_HALVES: Set = Set {
+    full: Cow::Borrowed("●"),
+    empty: Cow::Borrowed(" "),
+    symbols: frames!["◐", "◓", "◑", "◒"],
+    interval: millis(50),
+    durations: Cow::Borrowed(&[]),
+    outcomes: OUTCOME_UNICODE,
+};
+
+/// `squish`: `["╫", "╪"]`
+pub const SQUISH: Set = Set {
+    full: Cow::Borrowed("╫"),
+    empty: Cow::Borrowed(" "),
+    symbols: frames!["╫", "╪"],
+    interval: millis(100),
+    durations: Cow::Borrowed(&[]),
+    outcomes: OUTCOME_UNICODE,
+};
+
+/// `toggle`: `["⊶", "⊷"]`
+pub const TOGGLE: Set = Set {
+    full: Cow::Borrowed("⊶"),
+    empty: Cow::Borrowed(" "),
+    symbols: frames!["⊶", "⊷"],
+    interval: millis(250),
+    durations: Cow::Borrowed(&[]),
+    outcomes: OUTCOME_UNICODE,
+};
+
+/// `toggle2`: `["▫", "▪"]`
+pub const TOGGLE2: Set = Set {
+    full: Cow::Borrowed("▪"),
+    empty: Cow::Borrowed(" "),
+    symbols: frames!["▫", "▪"],
+    interval: millis(80),
+    durations: Cow::Borrowed(&[]),
+    outcomes: OUTCOME_UNICODE,
+};
+
+/// `toggle3`: `["□", "■"]`
+pub const TOGGLE3: Set = Set {
+    full: Cow::Borrowed("■"),
+    empty: Cow::Borrowed(" "),
+    symbols: frames!["□", "■"],
+    interval: millis(120),
+    durations: Cow::Borrowed(&[]),
+    outcomes: OUTCOME_UNICODE,
+};
+
+/// `toggle4`: `["■", "□", "▪", "▫"]`
+pub const TOGGLE4: Set = Set {
+    full: Cow::Borrowed("■"),
+    empty: Cow::Borrowed(" "),
+    symbols: frames!["■", "□", "▪", "▫"],
+    interval: millis(100),
+    durations: Cow::Borrowed(&[]),
+    outcomes: OUTCOME_UNICODE,
+};
+
+/// `toggle5`: `["▮", "▯"]`
+pub const TOGGLE5: Set = Set {
+    full: Cow::Borrowed("▮"),
+    empty: Cow::Borrowed(" "),
+    symbols: frames!["▮", "▯"],
+    interval: millis(100),
+    durations: Cow::Borrowed(&[]),
+    outcomes: OUTCOME_UNICODE,
+};
+
+/// `toggle6`: `["ဝ", "၀"]`
+pub const TOGGLE6: Set = Set {
+    full: Cow::Borrowed("ဝ"),
+    empty: Cow::Borrowed(" "),
+    symbols: frames!["ဝ", "၀"],
+    interval: millis(300),
+    durations: Cow::Borrowed(&[]),
+    outcomes: OUTCOME_UNICODE,
+};
+
+/// `toggle7`: `["⦾", "⦿"]`
+pub const TOGGLE7: Set = Set {
+    full: Cow::Borrowed("⦿"),
+    empty: Cow::Borrowed(" "),
+    symbols: frames!["⦾", "⦿"],
+    interval: millis(80),
+    durations: Cow::Borrowed(&[]),
+    outcomes: OUTCOME_UNICODE,
+};
+
+/// `toggle8`: `["◍", "◌"]`
+pub const TOGGLE8: Set = Set {
+    full: Cow::Borrowed("◍"),
+    empty: Cow::Borrowed(" "),
+    symbols: frames!["◍", "◌"],
+    interval: millis(100),
+    durations: Cow::Borrowed(&[]),
+    outcomes: OUTCOME_UNICODE,
+};
+
+/// `toggle9`: `["◉", "◎"]`
+pub const TOGGLE9: Set = Set {
+    full: Cow::Borrowed("◉"),
+    empty: Cow::Borrowed(" "),
+    symbols: frames!["◉", "◎"],
+    interval: millis(100),
+    durations: Cow::Borrowed(&[]),
+    outcomes: OUTCOME_UNICODE,
+};
+
+/// `toggle10`: `["㊂", "㊀", "㊁"]`
+pub const TOGGLE10: Set = Set {
+    full: Cow::Borrowed("㊂"),
+    empty: Cow::Borrowed("  "),
+    symbols: frames!["㊂", "㊀", "㊁"],
+    interval: millis(100),
+    durations: Cow::Borrowed(&[]),
+    outcomes: OUTCOME_UNICODE,
+};
+
+/// `toggle11`: `["⧇", "⧆"]`
+pub const TOGGLE11: Set = Set {
+    full: Cow::Borrowed("⧇"),
+    empty: Cow::Borrowed(" "),
+    symbols: frames!["⧇", "⧆"],
+    interval: millis(50),
+    durations: Cow::Borrowed(&[]),
+    outcomes: OUTCOME_UNICODE,
+};
+
+/// `toggle12`: `["☗", "☖"]`
+pub const TOGGLE12: Set = Set {
+    full: Cow::Borrowed("☗"),
+    empty: Cow::Borrowed(" "),
+    symbols: frames!["☗", "☖"],
+    interval: millis(120),
+    durations: Cow::Borrowed(&[]),
+    outcomes: OUTCOME_UNICODE,
+};
+
+/// `toggle13`: `["=", "*", "-"]`
+pub const TOGGLE13: Set = Set {
+    full: Cow::Borrowed("="),
+    empty: Cow::Borrowed(" "),
+    symbols: frames!["=", "*", "-"],
+    interval: millis(80),
+    durations: Cow::Borrowed(&[]),
+    outcomes: OUTCOME_UNICODE,
+};
+
+/// `arrow`: `["←", "↖", "↑", "↗", "→", "↘", "↓", "↙"]`
+pub const ARROW: Set = Set {
+    full: Cow::Borrowed("←"),
+    empty: Cow::Borrowed(" "),
+    symbols: frames!["←", "↖", "↑", "↗", "→", "↘", "↓", "↙"],
+    interval: millis(100),
+    durations: Cow::Borrowed(&[]),
+    outcomes: OUTCOME_UNICODE,
+};
+
+/// `arrow2`: `["⬆️", "↗️", "➡️", "↘️", "⬇️", "↙️", "⬅️", "↖️"]`
+pub const ARROW2: Set = Set {
+    full: Cow::Borrowed("⬆️"),
+    empty: Cow::Borrowed("  "),
+    symbols: frames!["⬆️", "↗️", "➡️", "↘️", "⬇️", "↙️", "⬅️", "↖️"],
+    interval: millis(80),
+    durations: Cow::Borrowed(&[]),
+    outcomes: OUTCOME_UNICODE,
+};
+
+/// `arrow3`: `["▹▹▹▹▹", "▸▹▹▹▹", "▹▸▹▹▹", "▹▹▸▹▹", "▹▹▹▸▹", "▹▹▹▹▸"]`
+pub const ARROW3: Set = Set {
+    full: Cow::Borrowed("▸▸▸▸▸"),
+    empty: Cow::Borrowed("     "),
+    symbols: frames!["▹▹▹▹▹", "▸▹▹▹▹", "▹▸▹▹▹", "▹▹▸▹▹", "▹▹▹▸▹", "▹▹▹▹▸"],
+    interval: millis(120),
+    durations: Cow::Borrowed(&[]),
+    outcomes: OUTCOME_UNICODE,
+};
+
+/// `bouncingBar`: `["[    ]", "[=   ]", "[==  ]", "[=== ]", "[ ===]", "[  ==]", ...]`
+pub const BOUNCING_BAR: Set = Set {
+    full: Cow::Borrowed("[====]"),
+    empty: Cow::Borrowed("      "),
+    symbols: frames![
+        "[    ]", "[=   ]", "[==  ]", "[=== ]", "[ ===]", "[  ==]", "[   =]", "[    ]", "[   =]",
+        "[  ==]", "[ ===]", "[====]", "[=== ]", "[==  ]", "[=   ]"
+    ],
+    interval: millis(80),
+    durations: Cow::Borrowed(&[]),
+    outcomes: OUTCOME_UNICODE,
+};
+
+/// `bouncingBall`: `["( ●    )", "(  ●   )", "(   ●  )", "(    ● )", "(     ●)", "(    ● )", ...]`
+pub const BOUNCING_BALL: Set = Set {
+    full: Cow::Borrowed("( ●    )"),
+    empty: Cow::Borrowed("        "),
+    symbols: frames![
+        "( ●    )",
+        "(  ●   )",
+        "(   ●  )",
+        "(    ● )",
+        "(     ●)",
+        "(    ● )",
+        "(   ●  )",
+        "(  ●   )",
+        "( ●    )",
+        "(●     )"
+    ],
+    interval: millis(80),
+    durations: Cow::Borrowed(&[]),
+    outcomes: OUTCOME_UNICODE,
+};
+
+/// `smiley`: `["😄", "😝"]`
+pub const SMILEY: Set = Set {
+    full: Cow::Borrowed("😄"),
+    empty: Cow::Borrowed("  "),
+    symbols: frames!["😄", "😝"],
+    interval: millis(200),
+    durations: Cow::Borrowed(&[]),
+    outcomes: OUTCOME_UNICODE,
+};
+
+/// `monkey`: `["🙈", "🙈", "🙉", "🙊"]`
+pub const MONKEY: Set = Set {
+    full: Cow::Borrowed("🙈"),
+    empty: Cow::Borrowed("  "),
+    symbols: frames!["🙈", "🙈", "🙉", "🙊"],
+    interval: millis(300),
+    durations: Cow::Borrowed(&[]),
+    outcomes: OUTCOME_UNICODE,
+};
+
+/// `hearts`: `["💛", "💙", "💜", "💚", "❤️"]`
+pub const HEARTS: Set = Set {
+    full: Cow::Borrowed("💛"),
+    empty: Cow::Borrowed("  "),
+    symbols: frames!["💛", "💙", "💜", "💚", "❤️"],
+    interval: millis(100),
+    durations: Cow::Borrowed(&[]),
+    outcomes: OUTCOME_UNICODE,
+};
+
+/// `clock`: `["🕛", "🕐", "🕑", "🕒", "🕓", "🕔", ...]`
+pub const CLOCK: Set = Set {
+    full: Cow::Borrowed("🕛"),
+    empty: Cow::Borrowed("  "),
+    symbols: frames![
+        "🕛", "🕐", "🕑", "🕒", "🕓", "🕔", "🕕", "🕖", "🕗", "🕘", "🕙", "🕚"
+    ],
+    interval: millis(100),
+    durations: Cow::Borrowed(&[]),
+    outcomes: OUTCOME_UNICODE,
+};
+
+/// `earth`: `["🌍", "🌎", "🌏"]`
+pub const EARTH: Set = Set {
+    full: Cow::Borrowed("🌍"),
+    empty: Cow::Borrowed("  "),
+    symbols: frames!["🌍", "🌎", "🌏"],
+    interval: millis(180),
+    durations: Cow::Borrowed(&[]),
+    outcomes: OUTCOME_UNICODE,
+};
+
+/// `material`: `["█▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁", "██▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁", "███▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁", "████▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁", "██████▁▁▁▁▁▁▁▁▁▁▁▁▁▁", "██████▁▁▁▁▁▁▁▁▁▁▁▁▁▁", ...]`
+pub const MATERIAL: Set = Set {
+    full: Cow::Borrowed("████████████████████"),
+    empty: Cow::Borrowed("                    "),
+    symbols: frames![
+        "█▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁",
+        "██▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁",
+        "███▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁",
+        "████▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁",
+        "██████▁▁▁▁▁▁▁▁▁▁▁▁▁▁",
+        "██████▁▁▁▁▁▁▁▁▁▁▁▁▁▁",
+        "███████▁▁▁▁▁▁▁▁▁▁▁▁▁",
+        "████████▁▁▁▁▁▁▁▁▁▁▁▁",
+        "█████████▁▁▁▁▁▁▁▁▁▁▁",
+        "█████████▁▁▁▁▁▁▁▁▁▁▁",
+        "██████████▁▁▁▁▁▁▁▁▁▁",
+        "███████████▁▁▁▁▁▁▁▁▁",
+        "█████████████▁▁▁▁▁▁▁",
+        "██████████████▁▁▁▁▁▁",
+        "██████████████▁▁▁▁▁▁",
+        "▁██████████████▁▁▁▁▁",
+        "▁██████████████▁▁▁▁▁",
+        "▁██████████████▁▁▁▁▁",
+        "▁▁██████████████▁▁▁▁",
+        "▁▁▁██████████████▁▁▁",
+        "▁▁▁▁█████████████▁▁▁",
+        "▁▁▁▁██████████████▁▁",
+        "▁▁▁▁██████████████▁▁",
+        "▁▁▁▁▁██████████████▁",
+        "▁▁▁▁▁██████████████▁",
+        "▁▁▁▁▁██████████████▁",
+        "▁▁▁▁▁▁██████████████",
+        "▁▁▁▁▁▁██████████████",
+        "▁▁▁▁▁▁▁█████████████",
+        "▁▁▁▁▁▁▁█████████████",
+        "▁▁▁▁▁▁▁▁████████████",
+        "▁▁▁▁▁▁▁▁████████████",
+        "▁▁▁▁▁▁▁▁▁███████████",
+        "▁▁▁▁▁▁▁▁▁███████████",
+        "▁▁▁▁▁▁▁▁▁▁██████████",
+        "▁▁▁▁▁▁▁▁▁▁██████████",
+        "▁▁▁▁▁▁▁▁▁▁▁▁████████",
+        "▁▁▁▁▁▁▁▁▁▁▁▁▁███████",
+        "▁▁▁▁▁▁▁▁▁▁▁▁▁▁██████",
+        "▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁█████",
+        "▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁█████",
+        "█▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁████",
+        "██▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁███",
+        "██▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁███",
+        "███▁▁▁▁▁▁▁▁▁▁▁▁▁▁███",
+        "████▁▁▁▁▁▁▁▁▁▁▁▁▁▁██",
+        "█████▁▁▁▁▁▁▁▁▁▁▁▁▁▁█",
+        "█████▁▁▁▁▁▁▁▁▁▁▁▁▁▁█",
+        "██████▁▁▁▁▁▁▁▁▁▁▁▁▁█",
+        "████████▁▁▁▁▁▁▁▁▁▁▁▁",
+        "█████████▁▁▁▁▁▁▁▁▁▁▁",
+        "█████████▁▁▁▁▁▁▁▁▁▁▁",
+        "█████████▁▁▁▁▁▁▁▁▁▁▁",
+        "█████████▁▁▁▁▁▁▁▁▁▁▁",
+        "███████████▁▁▁▁▁▁▁▁▁",
+        "████████████▁▁▁▁▁▁▁▁",
+        "████████████▁▁▁▁▁▁▁▁",
+        "██████████████▁▁▁▁▁▁",
+        "██████████████▁▁▁▁▁▁",
+        "▁██████████████▁▁▁▁▁",
+        "▁██████████████▁▁▁▁▁",
+        "▁▁▁█████████████▁▁▁▁",
+        "▁▁▁▁▁████████████▁▁▁",
+        "▁▁▁▁▁████████████▁▁▁",
+        "▁▁▁▁▁▁███████████▁▁▁",
+        "▁▁▁▁▁▁▁▁█████████▁▁▁",
+        "▁▁▁▁▁▁▁▁█████████▁▁▁",
+        "▁▁▁▁▁▁▁▁▁█████████▁▁",
+        "▁▁▁▁▁▁▁▁▁█████████▁▁",
+        "▁▁▁▁▁▁▁▁▁▁█████████▁",
+        "▁▁▁▁▁▁▁▁▁▁▁████████▁",
+        "▁▁▁▁▁▁▁▁▁▁▁████████▁",
+        "▁▁▁▁▁▁▁▁▁▁▁▁███████▁",
+        "▁▁▁▁▁▁▁▁▁▁▁▁███████▁",
+        "▁▁▁▁▁▁▁▁▁▁▁▁▁███████",
+        "▁▁▁▁▁▁▁▁▁▁▁▁▁███████",
+        "▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁█████",
+        "▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁████",
+        "▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁████",
+        "▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁████",
+        "▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁███",
+        "▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁███",
+        "▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁██",
+        "▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁██",
+        "▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁██",
+        "▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁█",
+        "▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁█",
+        "▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁█",
+        "▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁",
+        "▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁",
+        "▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁",
+        "▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁"
+    ],
+    interval: millis(17),
+    durations: Cow::Borrowed(&[]),
+    outcomes: OUTCOME_UNICODE,
+};
+
+/// `moon`: `["🌑", "🌒", "🌓", "🌔", "🌕", "🌖", "🌗", "🌘"]`
+pub const MOON: Set = Set {
+    full: Cow::Borrowed("🌕"),
+    empty: Cow::Borrowed("  "),
+    symbols: frames!["🌑", "🌒", "🌓", "🌔", "🌕", "🌖", "🌗", "🌘"],
+    interval: millis(80),
+    durations: Cow::Borrowed(&[]),
+    outcomes: OUTCOME_UNICODE,
+};
+
+/// `runner`: `["🚶", "🏃"]`
+pub const RUNNER: Set = Set {
+    full: Cow::Borrowed("🚶"),
+    empty: Cow::Borrowed("  "),
+    symbols: frames!["🚶", "🏃"],
+    interval: millis(140),
+    durations: Cow::Borrowed(&[]),
+    outcomes: OUTCOME_UNICODE,
+};
+
+/// `pong`: `["▐⠂       ▌", "▐⠈       ▌", "▐ ⠂      ▌", "▐ ⠠      ▌", "▐  ⡀     ▌", "▐  ⠠     ▌", ...]`
+pub const PONG: Set = Set {
+    full: Cow::Borrowed("▐⠂       ▌"),
+    empty: Cow::Borrowed("          "),
+    symbols: frames![
+        "▐⠂       ▌",
+        "▐⠈       ▌",
+        "▐ ⠂      ▌",
+        "▐ ⠠      ▌",
+        "▐  ⡀     ▌",
+        "▐  ⠠     ▌",
+        "▐   ⠂    ▌",
+        "▐   ⠈    ▌",
+        "▐    ⠂   ▌",
+        "▐    ⠠   ▌",
+        "▐     ⡀  ▌",
+        "▐     ⠠  ▌",
+        "▐      ⠂ ▌",
+        "▐      ⠈ ▌",
+        "▐       ⠂▌",
+        "▐       ⠠▌",
+        "▐       ⡀▌",
+        "▐      ⠠ ▌",
+        "▐      ⠂ ▌",
+        "▐     ⠈  ▌",
+        "▐     ⠂  ▌",
+        "▐    ⠠   ▌",
+        "▐    ⡀   ▌",
+        "▐   ⠠    ▌",
+        "▐   ⠂    ▌",
+        "▐  ⠈     ▌",
+        "▐  ⠂     ▌",
+        "▐ ⠠      ▌",
+        "▐ ⡀      ▌",
+        "▐⠠       ▌"
+    ],
+    interval: millis(80),
+    durations: Cow::Borrowed(&[]),
+    outcomes: OUTCOME_UNICODE,
+};
+
+/// `shark`: `["▐|\\____________▌", "▐_|\\___________▌", "▐__|\\__________▌", "▐___|\\_________▌", "▐____|\\________▌", "▐_____|\\_______▌", ...]`
+pub const SHARK: Set = Set {
+    full: Cow::Borrowed("▐|\\____________▌"),
+    empty: Cow::Borrowed("                "),
+    symbols: frames![
+        "▐|\\____________▌",
+        "▐_|\\___________▌",
+        "▐__|\\__________▌",
+        "▐___|\\_________▌",
+        "▐____|\\________▌",
+        "▐_____|\\_______▌",
+        "▐______|\\______▌",
+        "▐_______|\\_____▌",
+        "▐________|\\____▌",
+        "▐_________|\\___▌",
+        "▐__________|\\__▌",
+        "▐___________|\\_▌",
+        "▐____________|\\▌",
+        "▐____________/|▌",
+        "▐___________/|_▌",
+        "▐__________/|__▌",
+        "▐_________/|___▌",
+        "▐________/|____▌",
+        "▐_______/|_____▌",
+        "▐______/|______▌",
+        "▐_____/|_______▌",
+        "▐____/|________▌",
+        "▐___/|_________▌",
+        "▐__/|__________▌",
+        "▐_/|___________▌",
+        "▐/|____________▌"
+    ],
+    interval: millis(120),
+    durations: Cow::Borrowed(&[]),
+    outcomes: OUTCOME_UNICODE,
+};
+
+/// `dqpb`: `["d", "q", "p", "b"]`
+pub const DQPB: Set = Set {
+    full: Cow::Borrowed("d"),
+    empty: Cow::Borrowed(" "),
+    symbols: frames!["d", "q", "p", "b"],
+    interval: millis(100),
+    durations: Cow::Borrowed(&[]),
+    outcomes: OUTCOME_UNICODE,
+};
+
+/// `weather`: `["☀️", "☀️", "☀️", "🌤 ", "⛅️", "🌥 ", ...]`
+pub const WEATHER: Set = Set {
+    full: Cow::Borrowed("☀️"),
+    empty: Cow::Borrowed("  "),
+    symbols: frames![
+        "☀️", "☀️", "☀️", "🌤 ", "⛅️", "🌥 ", "☁️", "🌧 ", "🌨 ", "🌧 ", "🌨 ", "🌧 ", "🌨 ", "⛈ ", "🌨 ",
+        "🌧 ", "🌨 ", "☁️", "🌥 ", "⛅️", "🌤 ", "☀️", "☀️"
+    ],
+    interval: millis(100),
+    durations: Cow::Borrowed(&[]),
+    outcomes: OUTCOME_UNICODE,
+};
+
+/// `christmas`: `["🌲", "🎄"]`
+pub const CHRISTMAS: Set = Set {
+    full: Cow::Borrowed("🌲"),
+    empty: Cow::Borrowed("  "),
+    symbols: frames!["🌲", "🎄"],
+    interval: millis(400),
+    durations: Cow::Borrowed(&[]),
+    outcomes: OUTCOME_UNICODE,
+};
+
+/// `grenade`: `["،  ", "′  ", " ´ ", " ‾ ", "  ⸌", "  ⸊", ...]`
+pub const GRENADE: Set = Set {
+    full: Cow::Borrowed("،  "),
+    empty: Cow::Borrowed("   "),
+    symbols: frames![
+        "،  ", "′  ", " ´ ", " ‾ ", "  ⸌", "  ⸊", "  |", "  ⁎", "  ⁕", " ෴ ", "  ⁓", "   ", "   ",
+        "   "
+    ],
+    interval: millis(80),
+    durations: Cow::Borrowed(&[]),
+    outcomes: OUTCOME_UNICODE,
+};
+
+/// `point`: `["∙∙∙", "●∙∙", "∙●∙", "∙∙●", "∙∙∙"]`
+pub const POINT: Set = Set {
+    full: Cow::Borrowed("●●●"),
+    empty: Cow::Borrowed("   "),
+    symbols: frames!["∙∙∙", "●∙∙", "∙●∙", "∙∙●", "∙∙∙"],
+    interval: millis(125),
+    durations: Cow::Borrowed(&[]),
+    outcomes: OUTCOME_UNICODE,
+};
+
+/// `layer`: `["-", "=", "≡"]`
+pub const LAYER: Set = Set {
+    full: Cow::Borrowed("≡"),
+    empty: Cow::Borrowed(" "),
+    symbols: frames!["-", "=", "≡"],
+    interval: millis(150),
+    durations: Cow::Borrowed(&[]),
+    outcomes: OUTCOME_UNICODE,
+};
+
+/// `betaWave`: `["ρββββββ", "βρβββββ", "ββρββββ", "βββρβββ", "ββββρββ", "βββββρβ", "ββββββρ"]`
+pub const BETA_WAVE: Set = Set {
+    full: Cow::Borrowed("ρββββββ"),
+    empty: Cow::Borrowed("       "),
+    symbols: frames![
+        "ρββββββ",
+        "βρβββββ",
+        "ββρββββ",
+        "βββρβββ",
+        "ββββρββ",
+        "βββββρβ",
+        "ββββββρ"
+    ],
+    interval: millis(80),
+    durations: Cow::Borrowed(&[]),
+    outcomes: OUTCOME_UNICODE,
+};
+
+/// `fingerDance`: `["🤘", "🤟", "🖖", "✋", "🤚", "👆"]`
+pub const FINGER_DANCE: Set = Set {
+    full: Cow::Borrowed("🤘"),
+    empty: Cow::Borrowed("  "),
+    symbols: frames!["🤘", "🤟", "🖖", "✋", "🤚", "👆"],
+    interval: millis(160),
+    durations: Cow::Borrowed(&[]),
+    outcomes: OUTCOME_UNICODE,
+};
+
+/// `fistBump`: `["🤜　　　　🤛", "🤜　　　　🤛", "🤜　　　　🤛", "　🤜　　🤛　", "　　🤜🤛　　", "　🤜✨🤛　　", "🤜　✨　🤛　"]`
+pub const FIST_BUMP: Set = Set {
+    full: Cow::Borrowed("　🤜✨🤛　　"),
+    empty: Cow::Borrowed("            "),
+    symbols: frames![
+        "🤜　　　　🤛",
+        "🤜　　　　🤛",
+        "🤜　　　　🤛",
+        "　🤜　　🤛　",
+        "　　🤜🤛　　",
+        "　🤜✨🤛　　",
+        "🤜　✨　🤛　"
+    ],
+    interval: millis(80),
+    durations: Cow::Borrowed(&[]),
+    outcomes: OUTCOME_UNICODE,
+};
+
+/// `soccerHeader`: `[" 🧑⚽️       🧑", "🧑  ⚽️      🧑", "🧑   ⚽️     🧑", "🧑    ⚽️    🧑", "🧑     ⚽️   🧑", "🧑      ⚽️  🧑", ...]`
+pub const SOCCER_HEADER: Set = Set {
+    full: Cow::Borrowed(" 🧑⚽️       🧑"),
+    empty: Cow::Borrowed("              "),
+    symbols: frames![
+        " 🧑⚽️       🧑",
+        "🧑  ⚽️      🧑",
+        "🧑   ⚽️     🧑",
+        "🧑    ⚽️    🧑",
+        "🧑     ⚽️   🧑",
+        "🧑      ⚽️  🧑",
+        "🧑       ⚽️🧑 ",
+        "🧑      ⚽️  🧑",
+        "🧑     ⚽️   🧑",
+        "🧑    ⚽️    🧑",
+        "🧑   ⚽️     🧑",
+        "🧑  ⚽️      🧑"
+    ],
+    interval: millis(80),
+    durations: Cow::Borrowed(&[]),
+    outcomes: OUTCOME_UNICODE,
+};
+
+/// `mindblown`: `["😐", "😐", "😮", "😮", "😦", "😦", ...]`
+pub const MINDBLOWN: Set = Set {
+    full: Cow::Borrowed("🤯"),
+    empty: Cow::Borrowed("  "),
+    symbols: frames![
+        "😐", "😐", "😮", "😮", "😦", "😦", "😧", "😧", "🤯", "💥", "✨", "　", "　", "　"
+    ],
+    interval: millis(160),
+    durations: Cow::Borrowed(&[]),
+    outcomes: OUTCOME_UNICODE,
+};
+
+/// `speaker`: `["🔈", "🔉", "🔊", "🔉"]`
+pub const SPEAKER: Set = Set {
+    full: Cow::Borrowed("🔊"),
+    empty: Cow::Borrowed("  "),
+    symbols: frames!["🔈", "🔉", "🔊", "🔉"],
+    interval: millis(160),
+    durations: Cow::Borrowed(&[]),
+    outcomes: OUTCOME_UNICODE,
+};
+
+/// `orangePulse`: `["🔸", "🔶", "🟠", "🟠", "🔶"]`
+pub const ORANGE_PULSE: Set = Set {
+    full: Cow::Borrowed("🟠"),
+    empty: Cow::Borrowed("  "),
+    symbols: frames!["🔸", "🔶", "🟠", "🟠", "🔶"],
+    interval: millis(100),
+    durations: Cow::Borrowed(&[]),
+    outcomes: OUTCOME_UNICODE,
+};
+
+/// `bluePulse`: `["🔹", "🔷", "🔵", "🔵", "🔷"]`
+pub const BLUE_PULSE: Set = Set {
+    full: Cow::Borrowed("🔵"),
+    empty: Cow::Borrowed("  "),
+    symbols: frames!["🔹", "🔷", "🔵", "🔵", "🔷"],
+    interval: millis(100),
+    durations: Cow::Borrowed(&[]),
+    outcomes: OUTCOME_UNICODE,
+};
+
+/// `orangeBluePulse`: `["🔸", "🔶", "🟠", "🟠", "🔶", "🔹", ...]`
+pub const ORANGE_BLUE_PULSE: Set = Set {
+    full: Cow::Borrowed("🟠"),
+    empty: Cow::Borrowed("  "),
+    symbols: frames!["🔸", "🔶", "🟠", "🟠", "🔶", "🔹", "🔷", "🔵", "🔵", "🔷"],
+    interval: millis(100),
+    durations: Cow::Borrowed(&[]),
+    outcomes: OUTCOME_UNICODE,
+};
+
+/// `timeTravel`: `["🕛", "🕚", "🕙", "🕘", "🕗", "🕖", ...]`
+pub const TIME_TRAVEL: Set = Set {
+    full: Cow::Borrowed("🕛"),
+    empty: Cow::Borrowed("  "),
+    symbols: frames![
+        "🕛", "🕚", "🕙", "🕘", "🕗", "🕖", "🕕", "🕔", "🕓", "🕒", "🕑", "🕐"
+    ],
+    interval: millis(100),
+    durations: Cow::Borrowed(&[]),
+    outcomes: OUTCOME_UNICODE,
+};
+
+/// `aesthetic`: `["▰▱▱▱▱▱▱", "▰▰▱▱▱▱▱", "▰▰▰▱▱▱▱", "▰▰▰▰▱▱▱", "▰▰▰▰▰▱▱", "▰▰▰▰▰▰▱", "▰▰▰▰▰▰▰", "▰▱▱▱▱▱▱"]`
+pub const AESTHETIC: Set = Set {
+    full: Cow::Borrowed("▰▰▰▰▰▰▰"),
+    empty: Cow::Borrowed("       "),
+    symbols: frames![
+        "▰▱▱▱▱▱▱",
+        "▰▰▱▱▱▱▱",
+        "▰▰▰▱▱▱▱",
+        "▰▰▰▰▱▱▱",
+        "▰▰▰▰▰▱▱",
+        "▰▰▰▰▰▰▱",
+        "▰▰▰▰▰▰▰",
+        "▰▱▱▱▱▱▱"
+    ],
+    interval: millis(80),
+    durations: Cow::Borrowed(&[]),
+    outcomes: OUTCOME_UNICODE,
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::symbols::throbber::display_width as width;
+
+    #[test]
+    fn cli_spinners_have_consistent_width() {
+        for (name, set) in CLI_SPINNERS {
+            assert!(!set.symbols.is_empty(), "{name}");
+            assert!(!set.interval.is_zero(), "{name}");
+            let expected = width(&set.empty);
+            assert!(0 < expected, "{name}");
+            assert_eq!(width(&set.full), expected, "{name}");
+            for symbol in set.symbols.iter() {
+                assert_eq!(width(symbol), expected, "{name}: {symbol:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn cli_spinners_full_is_filled() {
+        assert_eq!(DOTS.full, "⠿");
+        assert_eq!(MATERIAL.full, "█".repeat(20));
+        assert_eq!(BOUNCING_BAR.full, "[====]");
+        // Spinners without a filled state use the first frame.
+        assert_eq!(ARROW.full, ARROW.symbols[0]);
+    }
+
+    #[test]
+    fn cli_spinners_are_looked_up_by_name() {
+        use crate::symbols::throbber::{builtin, SetRegistry};
+        assert_eq!(builtin("bouncingBar"), Some(&BOUNCING_BAR));
+        assert_eq!(SetRegistry::default().get("dots"), Some(&DOTS));
+        // The built-in sets take precedence.
+        assert_eq!(builtin("arrow"), Some(&crate::symbols::throbber::ARROW));
+        assert_eq!(
+            SetRegistry::default()
+                .iter()
+                .filter(|(name, _)| name.eq_ignore_ascii_case("arrow"))
+                .count(),
+            1
+        );
+        #[cfg(feature = "serde")]
+        {
+            let json = serde_json::to_string(&BOUNCING_BAR).unwrap();
+            assert_eq!(json, "\"bouncingBar\"");
+            assert_eq!(serde_json::from_str::<Set>(&json).unwrap(), BOUNCING_BAR);
+        }
+    }
+
+    #[test]
+    fn cli_spinners_have_unique_names() {
+        for (i, (name, _)) in CLI_SPINNERS.iter().enumerate() {
+            assert!(
+                CLI_SPINNERS[i + 1..]
+                    .iter()
+                    .all(|(other, _)| !other.eq_ignore_ascii_case(name)),
+                "{name}"
+            );
+        }
+    }
+}
@@ -0,0 +1,96 @@
+use alloc::format;
+use alloc::string::String;
+
+/// Smallest unit of the elapsed time to be displayed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ElapsedPrecision {
+    /// e.g. `1m 05s`
+    #[default]
+    Seconds,
+    /// e.g. `1m 05.3s`
+    Deciseconds,
+    /// e.g. `1m 05.321s`
+    Milliseconds,
+}
+
+/// Where the elapsed time is displayed relative to the label.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ElapsedPosition {
+    BeforeLabel,
+    #[default]
+    AfterLabel,
+}
+
+/// Format of the elapsed time displayed by Throbber.
+///
+/// The elapsed time is taken from `ThrobberState::elapsed()`.
+///
+/// # Examples:
+/// ```
+/// use throbber_widgets_tui::{ElapsedFormat, ElapsedPrecision};
+/// let format = ElapsedFormat::default();
+/// assert_eq!(format.format(std::time::Duration::from_millis(5_300)), "5s");
+/// assert_eq!(format.format(std::time::Duration::from_secs(65)), "1m 05s");
+/// assert_eq!(format.format(std::time::Duration::from_secs(3725)), "1h 02m 05s");
+///
+/// let format = ElapsedFormat::default().precision(ElapsedPrecision::Deciseconds);
+/// assert_eq!(format.format(std::time::Duration::from_millis(65_340)), "1m 05.3s");
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ElapsedFormat {
+    pub(crate) precision: ElapsedPrecision,
+    pub(crate) position: ElapsedPosition,
+    pub(crate) style: ratatui::style::Style,
+}
+
+impl ElapsedFormat {
+    pub fn precision(mut self, precision: ElapsedPrecision) -> Self {
+        self.precision = precision;
+        self
+    }
+
+    pub fn position(mut self, position: ElapsedPosition) -> Self {
+        self.position = position;
+        self
+    }
+
+    pub fn style(mut self, style: ratatui::style::Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Format the elapsed time in a human readable form.
+    pub fn format(&self, elapsed: core::time::Duration) -> String {
+        let secs = elapsed.as_secs();
+        let (hours, minutes, seconds) = (secs / 3600, secs / 60 % 60, secs % 60);
+        let fraction = match self.precision {
+            ElapsedPrecision::Seconds => String::new(),
+            ElapsedPrecision::Deciseconds => format!(".{}", elapsed.subsec_millis() / 100),
+            ElapsedPrecision::Milliseconds => format!(".{:03}", elapsed.subsec_millis()),
+        };
+        if 0 < hours {
+            format!("{hours}h {minutes:02}m {seconds:02}{fraction}s")
+        } else if 0 < minutes {
+            format!("{minutes}m {seconds:02}{fraction}s")
+        } else {
+            format!("{seconds}{fraction}s")
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn elapsed_format_precision() {
+        let elapsed = core::time::Duration::from_millis(3_723_045);
+        let format = ElapsedFormat::default();
+        assert_eq!(format.format(elapsed), "1h 02m 03s");
+        let format = format.precision(ElapsedPrecision::Deciseconds);
+        assert_eq!(format.format(elapsed), "1h 02m 03.0s");
+        let format = format.precision(ElapsedPrecision::Milliseconds);
+        assert_eq!(format.format(elapsed), "1h 02m 03.045s");
+        assert_eq!(format.format(core::time::Duration::ZERO), "0.000s");
+    }
+}
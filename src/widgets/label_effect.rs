@@ -0,0 +1,171 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// Animation of the label driven by the index of ThrobberState.
+///
+/// It is not applied when the task is finished.
+///
+/// # Examples:
+/// ```
+/// use throbber_widgets_tui::{LabelEffect, Throbber, ThrobberState};
+/// let throbber = Throbber::default()
+///     .label("Thinking")
+///     .label_effect(LabelEffect::Typewriter);
+/// let mut throbber_state = ThrobberState::default();
+/// throbber_state.calc_step(3);
+/// assert_eq!(throbber.to_line(&throbber_state).to_string(), "⠻ Thi     ");
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum LabelEffect {
+    #[default]
+    None,
+    /// A band of width characters patched with the style sweeps from left to right.
+    Shimmer {
+        width: u16,
+        style: ratatui::style::Style,
+    },
+    /// Each character has a color of the palette, and the colors flow from left to right.
+    Gradient(super::Palette),
+    /// Characters are revealed one by one, then the whole label is held for the same frames.
+    ///
+    /// Hidden characters are replaced with spaces of the same width, so the width does not change.
+    Typewriter,
+}
+
+impl LabelEffect {
+    /// Number of frames until the animation repeats for a label of len graphemes.
+    /// 0 if not animated.
+    pub(crate) fn period(&self, len: usize) -> i64 {
+        let len = len as i64;
+        match self {
+            Self::None => 0,
+            Self::Shimmer { width, .. } => len + *width as i64,
+            Self::Gradient(palette) => palette.len() as i64,
+            Self::Typewriter => len * 2,
+        }
+    }
+
+    /// Split a span of the label into spans styled for the frame at index.
+    ///
+    /// offset is the number of graphemes before the span, and len is that of the whole label.
+    pub(crate) fn spans<'a>(
+        &self,
+        label: &ratatui::text::Span<'a>,
+        index: i64,
+        offset: usize,
+        len: usize,
+    ) -> Vec<ratatui::text::Span<'a>> {
+        let period = self.period(len);
+        if period == 0 {
+            return alloc::vec![label.clone()];
+        }
+        let frame = index.rem_euclid(period);
+        let len = len as i64;
+
+        let mut spans: Vec<ratatui::text::Span<'a>> = Vec::new();
+        let graphemes = label.styled_graphemes(ratatui::style::Style::default());
+        for (i, grapheme) in graphemes.enumerate() {
+            let i = (offset + i) as i64;
+            let symbol = alloc::borrow::Cow::Borrowed(grapheme.symbol);
+            let (symbol, style) = match self {
+                Self::None => (symbol, label.style),
+                Self::Shimmer { width, style } => {
+                    let start = frame - *width as i64;
+                    if start <= i && i < start + *width as i64 {
+                        (symbol, label.style.patch(*style))
+                    } else {
+                        (symbol, label.style)
+                    }
+                }
+                Self::Gradient(palette) => {
+                    let style = match palette.color(i - frame) {
+                        Some(color) => label.style.fg(color),
+                        None => label.style,
+                    };
+                    (symbol, style)
+                }
+                Self::Typewriter if frame.min(len) <= i => {
+                    let width = crate::symbols::throbber::display_width(&symbol);
+                    (core::iter::repeat_n(' ', width).collect(), label.style)
+                }
+                Self::Typewriter => (symbol, label.style),
+            };
+            // Join the graphemes of the same style.
+            match spans.last_mut() {
+                Some(last) if last.style == style => last.content.to_mut().push_str(&symbol),
+                _ => spans.push(ratatui::text::Span::styled(String::from(symbol), style)),
+            }
+        }
+        spans
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn label_effect_shimmer_sweeps() {
+        let bold = ratatui::style::Style::default().add_modifier(ratatui::style::Modifier::BOLD);
+        let effect = LabelEffect::Shimmer {
+            width: 2,
+            style: bold,
+        };
+        let label = ratatui::text::Span::raw("abcd");
+        assert_eq!(effect.period(4), 6);
+
+        let lit = |index| -> Vec<String> {
+            effect
+                .spans(&label, index, 0, 4)
+                .into_iter()
+                .filter(|span| span.style == bold)
+                .map(|span| span.content.into_owned())
+                .collect()
+        };
+        assert_eq!(lit(0), Vec::<String>::new());
+        assert_eq!(lit(1), ["a"]);
+        assert_eq!(lit(3), ["bc"]);
+        assert_eq!(lit(5), ["d"]);
+        assert_eq!(lit(7), ["a"]);
+        assert_eq!(effect.spans(&label, 3, 0, 4).len(), 3);
+    }
+
+    #[test]
+    fn label_effect_typewriter_keeps_width() {
+        let label = ratatui::text::Span::raw("日本e\u{301}x");
+        let text = |index| -> String {
+            LabelEffect::Typewriter
+                .spans(&label, index, 0, 4)
+                .iter()
+                .map(|span| span.content.as_ref())
+                .collect()
+        };
+        assert_eq!(text(1), "日    ");
+        assert_eq!(text(3), "日本e\u{301} ");
+        for index in 0..8 {
+            assert_eq!(ratatui::text::Span::raw(text(index)).width(), label.width());
+        }
+    }
+
+    #[test]
+    fn label_effect_gradient_flows() {
+        use ratatui::style::Color;
+        let effect = LabelEffect::Gradient(crate::widgets::Palette::new([Color::Red, Color::Blue]));
+        let label = ratatui::text::Span::raw("abc");
+        let colors = |index| -> Vec<Option<Color>> {
+            effect
+                .spans(&label, index, 0, 3)
+                .iter()
+                .map(|span| span.style.fg)
+                .collect()
+        };
+        assert_eq!(
+            colors(0),
+            [Some(Color::Red), Some(Color::Blue), Some(Color::Red)]
+        );
+        assert_eq!(
+            colors(1),
+            [Some(Color::Blue), Some(Color::Red), Some(Color::Blue)]
+        );
+    }
+}
@@ -0,0 +1,102 @@
+use alloc::borrow::Cow;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// Cycling suffix appended to the label, like growing dots.
+///
+/// The suffix is padded to the widest frame, so the layout does not shift.
+/// It is driven by the index of ThrobberState, and hidden when the task is finished.
+///
+/// # Examples:
+/// ```
+/// use throbber_widgets_tui::{LabelSuffix, Throbber, ThrobberState};
+/// let throbber = Throbber::default()
+///     .label("Loading")
+///     .label_suffix(LabelSuffix::dots());
+/// let mut throbber_state = ThrobberState::default();
+/// assert_eq!(throbber.to_line(&throbber_state).to_string(), "⠷ Loading   ");
+/// throbber_state.calc_step(2);
+/// assert_eq!(throbber.to_line(&throbber_state).to_string(), "⠟ Loading.. ");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LabelSuffix {
+    frames: Vec<Cow<'static, str>>,
+    style: ratatui::style::Style,
+}
+
+impl Default for LabelSuffix {
+    fn default() -> Self {
+        Self::dots()
+    }
+}
+
+impl LabelSuffix {
+    /// Cycle the frames in order.
+    pub fn new<I, T>(frames: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<Cow<'static, str>>,
+    {
+        Self {
+            frames: frames.into_iter().map(Into::into).collect(),
+            style: ratatui::style::Style::default(),
+        }
+    }
+
+    /// `""`, `"."`, `".."`, `"..."`
+    pub fn dots() -> Self {
+        Self::new(["", ".", "..", "..."])
+    }
+
+    pub fn style(mut self, style: ratatui::style::Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Get the frames.
+    pub fn frames(&self) -> &[Cow<'static, str>] {
+        &self.frames
+    }
+
+    /// Get the display width of the widest frame.
+    pub fn width(&self) -> usize {
+        self.frames
+            .iter()
+            .map(|frame| crate::symbols::throbber::display_width(frame))
+            .max()
+            .unwrap_or_default()
+    }
+
+    /// Number of frames until the suffix repeats.
+    pub(crate) fn period(&self) -> i64 {
+        self.frames.len() as i64
+    }
+
+    /// Get the frame at index padded to the width.
+    pub(crate) fn span(&self, index: i64) -> Option<ratatui::text::Span<'static>> {
+        let len = self.frames.len() as i64;
+        let frame = self.frames.get(index.rem_euclid(len.max(1)) as usize)?;
+        let mut text = String::from(&**frame);
+        let padding = self
+            .width()
+            .saturating_sub(crate::symbols::throbber::display_width(frame));
+        text.extend(core::iter::repeat_n(' ', padding));
+        Some(ratatui::text::Span::styled(text, self.style))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn label_suffix_reserves_width() {
+        let suffix = LabelSuffix::new(["", " ⠁", " ⠁⠂"]);
+        assert_eq!(suffix.width(), 3);
+        let frames: Vec<_> = (0..4)
+            .map(|index| suffix.span(index).unwrap().content.into_owned())
+            .collect();
+        assert_eq!(frames, ["   ", " ⠁ ", " ⠁⠂", "   "]);
+        assert_eq!(LabelSuffix::new::<_, &str>([]).span(0), None);
+    }
+}
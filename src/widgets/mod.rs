@@ -1,4 +1,57 @@
+mod elapsed;
+mod label_effect;
+mod label_suffix;
+mod palette;
+mod progress;
+mod template;
 mod throbber;
+mod throbber_circle;
+mod throbber_list;
+mod throbber_tree;
+mod truncation;
 
+pub use self::elapsed::ElapsedFormat;
+pub use self::elapsed::ElapsedPosition;
+pub use self::elapsed::ElapsedPrecision;
+pub use self::label_effect::LabelEffect;
+pub use self::label_suffix::LabelSuffix;
+pub use self::palette::ColorMode;
+pub use self::palette::Palette;
+pub use self::progress::ProgressDisplay;
+pub use self::template::TemplateError;
+pub use self::template::ThrobberTemplate;
+pub use self::throbber::LabelPosition;
 pub use self::throbber::Throbber;
 pub use self::throbber::ThrobberState;
+pub use self::throbber_circle::ThrobberCircle;
+pub use self::throbber_list::ThrobberList;
+pub use self::throbber_list::ThrobberListState;
+pub use self::throbber_list::ThrobberTask;
+pub use self::throbber_tree::ThrobberTree;
+pub use self::throbber_tree::ThrobberTreeNode;
+pub use self::throbber_tree::ThrobberTreeState;
+pub use self::truncation::LabelTruncation;
+
+/// Render the widget in an area 20 cells wide and return its rows without trailing spaces.
+#[cfg(test)]
+fn render_rows<W>(
+    widget: W,
+    state: &mut W::State,
+    height: u16,
+) -> alloc::vec::Vec<alloc::string::String>
+where
+    W: ratatui::widgets::StatefulWidget,
+{
+    let area = ratatui::layout::Rect::new(0, 0, 20, height);
+    let mut buf = ratatui::buffer::Buffer::empty(area);
+    widget.render(area, &mut buf, state);
+    (0..height)
+        .map(|y| {
+            (0..area.width)
+                .map(|x| buf[(x, y)].symbol())
+                .collect::<alloc::string::String>()
+                .trim_end()
+                .into()
+        })
+        .collect()
+}
@@ -0,0 +1,253 @@
+use alloc::vec::Vec;
+
+use ratatui::style::Color;
+
+/// Colors that the terminal can display.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ColorMode {
+    /// 24-bit RGB colors.
+    #[default]
+    TrueColor,
+    /// 256 indexed colors. RGB colors are replaced with the nearest indexed color.
+    Indexed,
+}
+
+impl ColorMode {
+    /// Detect the color mode from the `COLORTERM` environment variable.
+    ///
+    /// TrueColor if it is `truecolor` or `24bit`, otherwise Indexed.
+    #[cfg(feature = "std")]
+    pub fn detect() -> Self {
+        match std::env::var("COLORTERM") {
+            Ok(colorterm) if colorterm == "truecolor" || colorterm == "24bit" => Self::TrueColor,
+            _ => Self::Indexed,
+        }
+    }
+}
+
+/// Colors of the symbol cycling per frame.
+///
+/// The color at the index of ThrobberState is used, so each frame has its own color.
+///
+/// # Examples:
+/// ```
+/// use ratatui::style::Color;
+/// use throbber_widgets_tui::{ColorMode, Palette};
+/// let palette = Palette::rainbow(6);
+/// assert_eq!(palette.color(1), Some(Color::Rgb(255, 255, 0)));
+///
+/// let palette = Palette::pulse(Color::Rgb(0, 0, 0), Color::Rgb(200, 100, 0), 3);
+/// assert_eq!(palette.len(), 4);
+/// assert_eq!(palette.color(1), Some(Color::Rgb(100, 50, 0)));
+/// assert_eq!(palette.color(3), Some(Color::Rgb(100, 50, 0)));
+///
+/// let palette = Palette::rainbow(6).color_mode(ColorMode::Indexed);
+/// assert_eq!(palette.color(0), Some(Color::Indexed(196)));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Palette {
+    colors: Vec<Color>,
+}
+
+impl Palette {
+    /// Cycle the colors in order.
+    pub fn new<I>(colors: I) -> Self
+    where
+        I: IntoIterator<Item = Color>,
+    {
+        Self {
+            colors: colors.into_iter().collect(),
+        }
+    }
+
+    /// Cycle the hue with steps colors.
+    pub fn rainbow(steps: u16) -> Self {
+        let steps = steps.max(1) as u32;
+        Self::new((0..steps).map(|i| {
+            let (r, g, b) = hue_to_rgb(i * 360 / steps);
+            Color::Rgb(r, g, b)
+        }))
+    }
+
+    /// Go back and forth between two colors, with steps colors in one direction.
+    ///
+    /// If either color can not be converted to RGB (e.g. Reset), they are just alternated.
+    pub fn pulse(from: Color, to: Color, steps: u16) -> Self {
+        let (Some(from), Some(to)) = (to_rgb(from), to_rgb(to)) else {
+            return Self::new([from, to]);
+        };
+        let steps = steps.max(2) as u32;
+        let forward = (0..steps).map(|i| lerp(from, to, i, steps - 1));
+        let backward = (1..steps - 1).rev().map(|i| lerp(from, to, i, steps - 1));
+        Self::new(forward.chain(backward))
+    }
+
+    /// Pulse between the color and its dimmed color, like breathing.
+    pub fn breathing(color: Color, steps: u16) -> Self {
+        match to_rgb(color) {
+            Some((r, g, b)) => {
+                let dim = |c: u8| (c as u16 * 3 / 10) as u8;
+                Self::pulse(color, Color::Rgb(dim(r), dim(g), dim(b)), steps)
+            }
+            None => Self::new([color]),
+        }
+    }
+
+    /// Degrade RGB colors for the color mode.
+    pub fn color_mode(mut self, mode: ColorMode) -> Self {
+        if mode == ColorMode::Indexed {
+            for color in &mut self.colors {
+                if let Color::Rgb(r, g, b) = *color {
+                    *color = Color::Indexed(rgb_to_indexed(r, g, b));
+                }
+            }
+        }
+        self
+    }
+
+    /// Get the color at index. Index is wrapped around by the number of colors.
+    pub fn color(&self, index: i64) -> Option<Color> {
+        let len = self.colors.len() as i64;
+        (0 < len).then(|| self.colors[index.rem_euclid(len) as usize])
+    }
+
+    /// Get all colors.
+    pub fn colors(&self) -> &[Color] {
+        &self.colors
+    }
+
+    pub fn len(&self) -> usize {
+        self.colors.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.colors.is_empty()
+    }
+}
+
+/// Convert the hue in degrees to RGB with full saturation and value.
+fn hue_to_rgb(hue: u32) -> (u8, u8, u8) {
+    let hue = hue % 360;
+    let rising = (255 * (hue % 60) / 60) as u8;
+    let falling = 255 - rising;
+    match hue / 60 {
+        0 => (255, rising, 0),
+        1 => (falling, 255, 0),
+        2 => (0, 255, rising),
+        3 => (0, falling, 255),
+        4 => (rising, 0, 255),
+        _ => (255, 0, falling),
+    }
+}
+
+fn lerp(from: (u8, u8, u8), to: (u8, u8, u8), i: u32, n: u32) -> Color {
+    let mix = |a: u8, b: u8| (a as i32 + (b as i32 - a as i32) * i as i32 / n as i32) as u8;
+    Color::Rgb(mix(from.0, to.0), mix(from.1, to.1), mix(from.2, to.2))
+}
+
+/// Levels of each channel of the 6x6x6 color cube in 256 indexed colors.
+const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+/// Colors of 0 to 15 in 256 indexed colors, same as xterm.
+const ANSI_COLORS: [(u8, u8, u8); 16] = [
+    (0, 0, 0),
+    (205, 0, 0),
+    (0, 205, 0),
+    (205, 205, 0),
+    (0, 0, 238),
+    (205, 0, 205),
+    (0, 205, 205),
+    (229, 229, 229),
+    (127, 127, 127),
+    (255, 0, 0),
+    (0, 255, 0),
+    (255, 255, 0),
+    (92, 92, 255),
+    (255, 0, 255),
+    (0, 255, 255),
+    (255, 255, 255),
+];
+
+/// Get the approximate RGB of the color. None if Reset.
+fn to_rgb(color: Color) -> Option<(u8, u8, u8)> {
+    let index = match color {
+        Color::Reset => return None,
+        Color::Rgb(r, g, b) => return Some((r, g, b)),
+        Color::Indexed(index) => index,
+        Color::Black => 0,
+        Color::Red => 1,
+        Color::Green => 2,
+        Color::Yellow => 3,
+        Color::Blue => 4,
+        Color::Magenta => 5,
+        Color::Cyan => 6,
+        Color::Gray => 7,
+        Color::DarkGray => 8,
+        Color::LightRed => 9,
+        Color::LightGreen => 10,
+        Color::LightYellow => 11,
+        Color::LightBlue => 12,
+        Color::LightMagenta => 13,
+        Color::LightCyan => 14,
+        Color::White => 15,
+    };
+    Some(match index {
+        0..=15 => ANSI_COLORS[index as usize],
+        16..=231 => {
+            let i = index - 16;
+            let level = |c: u8| CUBE_LEVELS[c as usize];
+            (level(i / 36), level(i / 6 % 6), level(i % 6))
+        }
+        _ => {
+            let gray = 8 + (index - 232) * 10;
+            (gray, gray, gray)
+        }
+    })
+}
+
+/// Get the nearest color of the color cube and the grayscale in 256 indexed colors.
+fn rgb_to_indexed(r: u8, g: u8, b: u8) -> u8 {
+    let distance = |(r2, g2, b2): (u8, u8, u8)| {
+        let d = |a: u8, b: u8| (a as i32 - b as i32).pow(2);
+        d(r, r2) + d(g, g2) + d(b, b2)
+    };
+    let nearest_level = |c: u8| {
+        (0..6u8)
+            .min_by_key(|&i| (CUBE_LEVELS[i as usize] as i32 - c as i32).abs())
+            .unwrap_or_default()
+    };
+    let cube = 16 + 36 * nearest_level(r) + 6 * nearest_level(g) + nearest_level(b);
+    let average = (r as u16 + g as u16 + b as u16) / 3;
+    let gray = 232 + (average.saturating_sub(3) / 10).min(23) as u8;
+    let rgb = |index| to_rgb(Color::Indexed(index)).unwrap_or_default();
+    if distance(rgb(gray)) < distance(rgb(cube)) {
+        gray
+    } else {
+        cube
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn palette_degrades_to_indexed() {
+        assert_eq!(rgb_to_indexed(255, 0, 0), 196);
+        assert_eq!(rgb_to_indexed(0, 0, 0), 16);
+        assert_eq!(rgb_to_indexed(128, 128, 128), 244);
+        assert_eq!(rgb_to_indexed(95, 135, 175), 67);
+        for index in 16..=255 {
+            let (r, g, b) = to_rgb(Color::Indexed(index)).unwrap();
+            assert_eq!(
+                to_rgb(Color::Indexed(rgb_to_indexed(r, g, b))),
+                Some((r, g, b))
+            );
+        }
+
+        let palette = Palette::breathing(Color::Rgb(200, 100, 0), 2).color_mode(ColorMode::Indexed);
+        assert_eq!(palette.colors(), [Color::Indexed(166), Color::Indexed(234)]);
+        let palette = Palette::pulse(Color::Reset, Color::Red, 5);
+        assert_eq!(palette.colors(), [Color::Reset, Color::Red]);
+    }
+}
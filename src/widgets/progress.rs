@@ -0,0 +1,76 @@
+use alloc::format;
+use alloc::string::String;
+
+/// How the progress is displayed next to the symbol when the total is known.
+///
+/// While `ThrobberState::total()` is None, nothing is displayed.
+///
+/// # Examples:
+/// ```
+/// use throbber_widgets_tui::ProgressDisplay;
+/// assert_eq!(ProgressDisplay::Percent.format(1, 3).unwrap(), "33%");
+/// assert_eq!(ProgressDisplay::Bar(4).format(5, 8).unwrap(), "██▌░");
+/// assert_eq!(ProgressDisplay::Hidden.format(5, 8), None);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ProgressDisplay {
+    Hidden,
+    /// e.g. `42%`
+    #[default]
+    Percent,
+    /// A mini bar of the width, e.g. `████▍░░░░░`
+    Bar(u16),
+}
+
+impl ProgressDisplay {
+    /// Format the position and the total.
+    ///
+    /// Returns None if Hidden or a Bar of width 0.
+    pub fn format(&self, position: u64, total: u64) -> Option<String> {
+        match self {
+            Self::Hidden | Self::Bar(0) => None,
+            Self::Percent => Some(format!("{}%", scale(position, total, 100))),
+            Self::Bar(width) => {
+                let eighths = scale(position, total, *width as u64 * 8);
+                let mut bar = String::new();
+                for i in 0..*width as u64 {
+                    let filled = eighths.saturating_sub(i * 8).min(8);
+                    bar.push_str(BAR_SYMBOLS[filled as usize]);
+                }
+                Some(bar)
+            }
+        }
+    }
+}
+
+/// Symbols of a mini bar cell filled by 0/8 to 8/8.
+const BAR_SYMBOLS: [&str; 9] = ["░", "▏", "▎", "▍", "▌", "▋", "▊", "▉", "█"];
+
+/// Scale position/total to 0..=max. If total is 0, it is complete.
+pub(crate) fn scale(position: u64, total: u64, max: u64) -> u64 {
+    if total == 0 {
+        return max;
+    }
+    (position.min(total) as u128 * max as u128 / total as u128) as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn progress_display_clamps() {
+        assert_eq!(ProgressDisplay::Percent.format(0, 10).unwrap(), "0%");
+        assert_eq!(ProgressDisplay::Percent.format(20, 10).unwrap(), "100%");
+        assert_eq!(ProgressDisplay::Percent.format(0, 0).unwrap(), "100%");
+        assert_eq!(
+            ProgressDisplay::Percent
+                .format(u64::MAX - 1, u64::MAX)
+                .unwrap(),
+            "99%"
+        );
+        assert_eq!(ProgressDisplay::Bar(3).format(0, 10).unwrap(), "░░░");
+        assert_eq!(ProgressDisplay::Bar(3).format(10, 10).unwrap(), "███");
+        assert_eq!(ProgressDisplay::Bar(0).format(10, 10), None);
+    }
+}
@@ -0,0 +1,298 @@
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::str::FromStr;
+
+use super::throbber::{Throbber, ThrobberState};
+
+/// Error of parsing ThrobberTemplate.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TemplateError {
+    /// `{` is not closed. The byte offset of `{` is held.
+    UnclosedBrace(usize),
+    /// `}` is not opened. Use `}}` for a literal `}`. The byte offset of `}` is held.
+    UnmatchedBrace(usize),
+    /// The placeholder key is not supported.
+    UnknownKey(String),
+    /// The style specifier is not a color or a modifier.
+    UnknownStyle(String),
+}
+
+impl core::fmt::Display for TemplateError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::UnclosedBrace(offset) => write!(f, "unclosed '{{' at {offset}"),
+            Self::UnmatchedBrace(offset) => write!(f, "unmatched '}}' at {offset}"),
+            Self::UnknownKey(key) => write!(f, "unknown placeholder '{key}'"),
+            Self::UnknownStyle(style) => write!(f, "unknown style '{style}'"),
+        }
+    }
+}
+
+impl core::error::Error for TemplateError {}
+
+/// Value to be replaced with a placeholder.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Key {
+    /// `{spinner}`: the symbol of Throbber.
+    Spinner,
+    /// `{msg}` or `{label}`: the label of Throbber.
+    Msg,
+    /// `{elapsed}`: the elapsed time of ThrobberState.
+    Elapsed,
+    /// `{pos}`: the position of ThrobberState.
+    Pos,
+    /// `{len}` or `{total}`: the total of ThrobberState, `?` if unknown.
+    Len,
+    /// `{percent}`: the progress of ThrobberState like `42%`, empty if the total is unknown.
+    Percent,
+}
+
+impl FromStr for Key {
+    type Err = TemplateError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "spinner" => Ok(Self::Spinner),
+            "msg" | "label" => Ok(Self::Msg),
+            "elapsed" => Ok(Self::Elapsed),
+            "pos" => Ok(Self::Pos),
+            "len" | "total" => Ok(Self::Len),
+            "percent" => Ok(Self::Percent),
+            _ => Err(TemplateError::UnknownKey(s.into())),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Part {
+    Text(String),
+    Placeholder(Key, ratatui::style::Style),
+}
+
+/// A template to format a throbber line, parsed once and reused.
+///
+/// Placeholders are enclosed in braces, optionally followed by a style after `:`.
+///
+/// - `{spinner}`: the symbol of Throbber padded to the width of the set (without the trailing space)
+/// - `{msg}` or `{label}`: the label of Throbber
+/// - `{elapsed}`: the elapsed time of ThrobberState
+/// - `{pos}`: the position of ThrobberState
+/// - `{len}` or `{total}`: the total of ThrobberState, `?` if unknown
+/// - `{percent}`: the progress of ThrobberState like `42%`, empty if the total is unknown
+///
+/// The style is colors and modifiers joined by `.`, e.g. `{spinner:red.bold}`.
+/// Background colors are prefixed with `on_`, e.g. `{msg:white.on_blue}`.
+/// Use `{{` and `}}` for literal braces.
+///
+/// # Examples:
+/// ```
+/// let template: throbber_widgets_tui::ThrobberTemplate =
+///     "{spinner:red} {msg} [{elapsed}] {pos}/{len}".parse().unwrap();
+/// let throbber = throbber_widgets_tui::Throbber::default().label("Downloading");
+/// let mut throbber_state = throbber_widgets_tui::ThrobberState::default();
+/// throbber_state.set_elapsed(Some(std::time::Duration::from_secs(5)));
+/// throbber_state.set_position(3);
+///
+/// let line = template.to_line(&throbber, &throbber_state);
+/// assert_eq!(line.to_string(), "⠷ Downloading [5s] 3/?");
+/// assert_eq!(line.spans[0].style.fg, Some(ratatui::style::Color::Red));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ThrobberTemplate {
+    parts: Vec<Part>,
+}
+
+impl ThrobberTemplate {
+    /// Parse the template string.
+    pub fn parse(template: &str) -> Result<Self, TemplateError> {
+        let mut parts = Vec::new();
+        let mut text = String::new();
+        let mut chars = template.char_indices().peekable();
+        while let Some((offset, c)) = chars.next() {
+            match c {
+                '{' if chars.next_if(|(_, c)| *c == '{').is_some() => text.push('{'),
+                '}' if chars.next_if(|(_, c)| *c == '}').is_some() => text.push('}'),
+                '}' => return Err(TemplateError::UnmatchedBrace(offset)),
+                '{' => {
+                    let rest = &template[offset + 1..];
+                    let end = rest.find('}').ok_or(TemplateError::UnclosedBrace(offset))?;
+                    if !text.is_empty() {
+                        parts.push(Part::Text(core::mem::take(&mut text)));
+                    }
+                    parts.push(parse_placeholder(&rest[..end])?);
+                    while chars.next_if(|(i, _)| *i <= offset + 1 + end).is_some() {}
+                }
+                _ => text.push(c),
+            }
+        }
+        if !text.is_empty() {
+            parts.push(Part::Text(text));
+        }
+        Ok(Self { parts })
+    }
+
+    /// Format the throbber and its state to Line.
+    ///
+    /// The style of Throbber is used as the base style of Line.
+    pub fn to_line<'a>(
+        &self,
+        throbber: &Throbber<'a>,
+        state: &ThrobberState,
+    ) -> ratatui::text::Line<'a> {
+        let mut state = state.clone();
+        let mut line = ratatui::text::Line::default().style(throbber.style);
+        for part in &self.parts {
+            match part {
+                Part::Text(text) => line.spans.push(ratatui::text::Span::raw(text.clone())),
+                Part::Placeholder(key, style) => {
+                    line.spans.extend(
+                        placeholder_spans(*key, throbber, &mut state)
+                            .into_iter()
+                            .map(|span| {
+                                let style = span.style.patch(*style);
+                                span.style(style)
+                            }),
+                    );
+                }
+            }
+        }
+        line
+    }
+}
+
+impl FromStr for ThrobberTemplate {
+    type Err = TemplateError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+/// Parse the content between braces like `spinner:red.bold`.
+fn parse_placeholder(content: &str) -> Result<Part, TemplateError> {
+    let (key, spec) = content.split_once(':').unwrap_or((content, ""));
+    let key = key.trim().parse()?;
+    let style = parse_style(spec)?;
+    Ok(Part::Placeholder(key, style))
+}
+
+/// Parse the style like `red.bold.on_blue`.
+fn parse_style(spec: &str) -> Result<ratatui::style::Style, TemplateError> {
+    let mut style = ratatui::style::Style::default();
+    for token in spec
+        .split('.')
+        .map(str::trim)
+        .filter(|token| !token.is_empty())
+    {
+        let unknown = || TemplateError::UnknownStyle(token.into());
+        if let Some(color) = token.strip_prefix("on_") {
+            style = style.bg(color.parse().map_err(|_| unknown())?);
+        } else if let Some(modifier) = ratatui::style::Modifier::from_name(&token.to_uppercase()) {
+            style = style.add_modifier(modifier);
+        } else {
+            style = style.fg(token.parse().map_err(|_| unknown())?);
+        }
+    }
+    Ok(style)
+}
+
+fn placeholder_spans<'a>(
+    key: Key,
+    throbber: &Throbber<'a>,
+    state: &mut ThrobberState,
+) -> Vec<ratatui::text::Span<'a>> {
+    let span = match key {
+        Key::Spinner => {
+            let (symbol, throbber_style) = throbber.padded_symbol(state);
+            ratatui::text::Span::styled(symbol, throbber_style)
+        }
+        Key::Msg => return throbber.label_text_spans(),
+        Key::Elapsed => {
+            let format = throbber.elapsed_format.clone().unwrap_or_default();
+            let text = state
+                .elapsed()
+                .map(|elapsed| format.format(elapsed))
+                .unwrap_or_default();
+            ratatui::text::Span::styled(text, format.style)
+        }
+        Key::Pos => ratatui::text::Span::raw(state.position().to_string()),
+        Key::Len => ratatui::text::Span::raw(
+            state
+                .total()
+                .map_or_else(|| String::from("?"), |total| total.to_string()),
+        ),
+        Key::Percent => ratatui::text::Span::raw(
+            state
+                .total()
+                .and_then(|total| super::ProgressDisplay::Percent.format(state.position(), total))
+                .unwrap_or_default(),
+        ),
+    };
+    alloc::vec![span]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn template_parses_braces() {
+        let template = ThrobberTemplate::parse("{{{pos}}} {label:bold.on_blue}").unwrap();
+        assert_eq!(
+            template.parts,
+            [
+                Part::Text("{".into()),
+                Part::Placeholder(Key::Pos, ratatui::style::Style::default()),
+                Part::Text("} ".into()),
+                Part::Placeholder(
+                    Key::Msg,
+                    ratatui::style::Style::default()
+                        .add_modifier(ratatui::style::Modifier::BOLD)
+                        .bg(ratatui::style::Color::Blue)
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn template_reports_errors() {
+        assert_eq!(
+            ThrobberTemplate::parse("ab {spinner"),
+            Err(TemplateError::UnclosedBrace(3))
+        );
+        assert_eq!(
+            ThrobberTemplate::parse("a}b"),
+            Err(TemplateError::UnmatchedBrace(1))
+        );
+        assert_eq!(
+            ThrobberTemplate::parse("{eta}"),
+            Err(TemplateError::UnknownKey("eta".into()))
+        );
+        assert_eq!(
+            ThrobberTemplate::parse("{msg:reddish}"),
+            Err(TemplateError::UnknownStyle("reddish".into()))
+        );
+    }
+
+    #[test]
+    fn template_renders_state() {
+        let template =
+            ThrobberTemplate::parse("{msg}: {spinner:green} {pos}/{total} {percent}").unwrap();
+        let throbber = Throbber::default()
+            .label("copy")
+            .use_type(crate::symbols::throbber::WhichUse::Full)
+            .throbber_style(ratatui::style::Style::default().bg(ratatui::style::Color::Black));
+        let mut throbber_state = ThrobberState::default();
+        throbber_state.set_total(Some(10));
+        throbber_state.inc(4);
+
+        let line = template.to_line(&throbber, &throbber_state);
+        assert_eq!(line.to_string(), "copy: ⠿ 4/10 40%");
+        assert_eq!(
+            line.spans[2].style,
+            ratatui::style::Style::default()
+                .fg(ratatui::style::Color::Green)
+                .bg(ratatui::style::Color::Black)
+        );
+    }
+}
@@ -1,23 +1,319 @@
 use alloc::string::String;
+use alloc::vec::Vec;
 
 #[cfg(feature = "rand")]
 use rand::Rng as _;
 
+/// Bounce and Sweep fall back to a symbol if the bar would be narrower than this.
+const MIN_BAR_WIDTH: u16 = 3;
+
+/// Least common multiple of positive numbers, saturating at i64::MAX.
+fn lcm(a: i64, b: i64) -> i64 {
+    let (mut x, mut y) = (a, b);
+    while y != 0 {
+        (x, y) = (y, x % y);
+    }
+    (a / x).saturating_mul(b)
+}
+
 /// State to be used for Throbber render.
+///
+/// The index is advanced either manually by `calc_next()`/`calc_step()`,
+/// or from the elapsed time after `start()` is called (time-driven).
+///
+/// With the `serde` feature, it can be (de)serialized except for the start instant of `start()`.
 #[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
 pub struct ThrobberState {
     /// Index of Set.symbols used when Spin is specified for WhichUse.
     ///
-    /// If out of range, it is normalized at render time.
-    index: i8,
+    /// At render time, it is reduced to the common period of the symbols and the animations,
+    /// which is a multiple of the number of symbols. See `normalize()`.
+    ///
+    /// It wraps around at `INDEX_PERIOD` in both directions.
+    index: i64,
+    /// Duration of one frame when time-driven.
+    ///
+    /// If None, Set.interval and Set.durations are used.
+    interval: Option<core::time::Duration>,
+    /// The final result of the task. If Some, it is rendered instead of spinning.
+    outcome: Option<crate::symbols::throbber::Outcome>,
+    /// The elapsed time supplied by the application or frozen at stop/finish.
+    elapsed: Option<core::time::Duration>,
+    /// The amount of work done.
+    position: u64,
+    /// The total amount of work. None means unknown.
+    total: Option<u64>,
+    /// The instant at which the time-driven animation started.
+    #[cfg(feature = "std")]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    started_at: Option<std::time::Instant>,
 }
 
 impl ThrobberState {
+    /// The index wraps around at this value.
+    ///
+    /// This is the least common multiple of 1 to 42, so the wrap never causes a jump
+    /// for sets with up to 42 symbols.
+    /// Longer sets (e.g. some of cli-spinners) would jump only once every 2.19e17 steps.
+    pub const INDEX_PERIOD: i64 = 219_060_189_739_591_200;
+
     /// Get a index.
-    pub fn index(&self) -> i8 {
+    ///
+    /// Migration from 0.11: the index was i8 and reset to 0 at the upper limit.
+    /// Now it is i64 and wraps around seamlessly at `ThrobberState::INDEX_PERIOD`.
+    pub fn index(&self) -> i64 {
         self.index
     }
 
+    /// Get the frame interval overriding the one of Set.
+    pub fn interval(&self) -> Option<core::time::Duration> {
+        self.interval
+    }
+
+    /// Set the frame interval used when time-driven.
+    ///
+    /// If None, the recommended timing of Set (`interval` and `durations`) is used.
+    ///
+    /// # Examples:
+    /// ```
+    /// let mut throbber_state = throbber_widgets_tui::ThrobberState::default();
+    /// assert_eq!(throbber_state.interval(), None);
+    /// throbber_state.set_interval(Some(std::time::Duration::from_millis(80)));
+    /// assert_eq!(throbber_state.interval(), Some(std::time::Duration::from_millis(80)));
+    /// ```
+    pub fn set_interval(&mut self, interval: Option<core::time::Duration>) {
+        self.interval = interval;
+    }
+
+    /// Start the time-driven animation from now.
+    ///
+    /// After this, render picks the frame from the elapsed time,
+    /// so the spin speed does not depend on the redraw cadence.
+    ///
+    /// # Examples:
+    /// ```
+    /// let mut throbber_state = throbber_widgets_tui::ThrobberState::default();
+    /// assert!(!throbber_state.is_time_driven());
+    /// throbber_state.start();
+    /// assert!(throbber_state.is_time_driven());
+    /// throbber_state.stop();
+    /// assert!(!throbber_state.is_time_driven());
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn start(&mut self) {
+        self.started_at = Some(std::time::Instant::now());
+    }
+
+    /// Stop the time-driven animation.
+    ///
+    /// The index stays at the last calculated frame, and `calc_next()`/`calc_step()` drive it again.
+    /// The elapsed time is frozen.
+    #[cfg(feature = "std")]
+    pub fn stop(&mut self) {
+        if let Some(started_at) = self.started_at.take() {
+            self.elapsed = Some(started_at.elapsed());
+        }
+    }
+
+    /// Whether `start()` has been called and not stopped.
+    #[cfg(feature = "std")]
+    pub fn is_time_driven(&self) -> bool {
+        self.started_at.is_some()
+    }
+
+    /// Get the elapsed time.
+    ///
+    /// While time-driven, it is the time since `start()`.
+    /// Otherwise, it is the time frozen at `stop()`/`finish()` or supplied by the application.
+    pub fn elapsed(&self) -> Option<core::time::Duration> {
+        #[cfg(feature = "std")]
+        if let Some(started_at) = self.started_at {
+            return Some(started_at.elapsed());
+        }
+        self.elapsed
+    }
+
+    /// Set the elapsed time.
+    ///
+    /// Without std, the application supplies the elapsed time by this or `calc_elapsed()`.
+    ///
+    /// # Examples:
+    /// ```
+    /// let mut throbber_state = throbber_widgets_tui::ThrobberState::default();
+    /// assert_eq!(throbber_state.elapsed(), None);
+    /// throbber_state.set_elapsed(Some(core::time::Duration::from_secs(65)));
+    /// let throbber = throbber_widgets_tui::Throbber::default()
+    ///     .label("Running")
+    ///     .elapsed_format(throbber_widgets_tui::ElapsedFormat::default());
+    /// assert_eq!(throbber.to_line(&throbber_state).to_string(), "⠷ Running 1m 05s");
+    /// ```
+    pub fn set_elapsed(&mut self, elapsed: Option<core::time::Duration>) {
+        self.elapsed = elapsed;
+    }
+
+    /// Get the outcome of the task.
+    ///
+    /// None means the task is still in progress.
+    pub fn outcome(&self) -> Option<crate::symbols::throbber::Outcome> {
+        self.outcome
+    }
+
+    /// Set the outcome of the task.
+    ///
+    /// While Some, the outcome symbol of Set is rendered instead of use_type.
+    /// Set None to spin again.
+    pub fn set_outcome(&mut self, outcome: Option<crate::symbols::throbber::Outcome>) {
+        self.outcome = outcome;
+    }
+
+    /// Finish the task with the outcome.
+    ///
+    /// If time-driven, it is stopped and the elapsed time is frozen.
+    ///
+    /// # Examples:
+    /// ```
+    /// use throbber_widgets_tui::{Outcome, Throbber, ThrobberState};
+    /// let mut throbber_state = ThrobberState::default();
+    /// let throbber = Throbber::default();
+    /// assert!(!throbber_state.is_finished());
+    ///
+    /// throbber_state.finish(Outcome::Success);
+    /// assert!(throbber_state.is_finished());
+    /// assert_eq!(throbber.to_symbol_span(&throbber_state).content, "✔ ");
+    /// ```
+    pub fn finish(&mut self, outcome: crate::symbols::throbber::Outcome) {
+        self.outcome = Some(outcome);
+        #[cfg(feature = "std")]
+        self.stop();
+    }
+
+    /// Whether the task has an outcome.
+    pub fn is_finished(&self) -> bool {
+        self.outcome.is_some()
+    }
+
+    /// Get the amount of work done.
+    pub fn position(&self) -> u64 {
+        self.position
+    }
+
+    /// Set the amount of work done.
+    pub fn set_position(&mut self, position: u64) {
+        self.position = position;
+    }
+
+    /// Increase the amount of work done.
+    ///
+    /// # Examples:
+    /// ```
+    /// let mut throbber_state = throbber_widgets_tui::ThrobberState::default();
+    /// throbber_state.set_total(Some(10));
+    /// throbber_state.inc(3);
+    /// throbber_state.inc(4);
+    /// assert_eq!(throbber_state.position(), 7);
+    /// assert_eq!(throbber_state.total(), Some(10));
+    /// ```
+    pub fn inc(&mut self, delta: u64) {
+        self.position = self.position.saturating_add(delta);
+    }
+
+    /// Get the total amount of work. None means unknown.
+    pub fn total(&self) -> Option<u64> {
+        self.total
+    }
+
+    /// Set the total amount of work. None means unknown.
+    pub fn set_total(&mut self, total: Option<u64>) {
+        self.total = total;
+    }
+
+    /// Calculate the index from the elapsed time.
+    ///
+    /// This is called from render function automatically when time-driven.
+    /// Without std, the application can supply the elapsed time itself.
+    /// The elapsed time is also kept for `elapsed()`.
+    ///
+    /// # Examples:
+    /// ```
+    /// let mut throbber_state = throbber_widgets_tui::ThrobberState::default();
+    /// throbber_state.set_interval(Some(core::time::Duration::from_millis(100)));
+    /// let throbber = throbber_widgets_tui::Throbber::default();
+    ///
+    /// throbber_state.calc_elapsed(core::time::Duration::from_millis(250), &throbber);
+    /// assert_eq!(throbber_state.index(), 2);
+    ///
+    /// throbber_state.calc_elapsed(core::time::Duration::from_millis(750), &throbber);
+    /// assert_eq!(throbber_state.index(), 7);
+    /// throbber_state.normalize(&throbber);
+    /// assert_eq!(throbber_state.index(), 1);
+    /// ```
+    pub fn calc_elapsed(&mut self, elapsed: core::time::Duration, throbber: &Throbber) {
+        self.elapsed = Some(elapsed);
+        let set = &throbber.throbber_set;
+        let len = set.symbols.len();
+        if len == 0 {
+            return;
+        }
+        let period = Self::INDEX_PERIOD as u128;
+        if throbber.is_bar() {
+            // One cell per interval, that is, one sub-cell per interval / len.
+            let interval = self.interval.unwrap_or(set.interval).as_nanos() / len as u128;
+            self.calc_intervals(elapsed, interval);
+        } else if let Some(interval) = self.interval {
+            self.calc_intervals(elapsed, interval.as_nanos());
+        } else {
+            let cycle = set.cycle_duration().as_nanos().max(1);
+            let cycles = elapsed.as_nanos() / cycle;
+            let mut rest = elapsed.as_nanos() % cycle;
+            let mut frame = len - 1;
+            for i in 0..len {
+                let duration = set.frame_duration(i).as_nanos();
+                if rest < duration {
+                    frame = i;
+                    break;
+                }
+                rest -= duration;
+            }
+            self.index = ((cycles % period * len as u128 + frame as u128) % period) as i64;
+        }
+    }
+
+    /// Calculate the index from the elapsed time by the interval if time-driven.
+    ///
+    /// The interval of the state takes precedence.
+    /// Used by widgets without Set.
+    pub(crate) fn calc_time_driven_by(&mut self, interval: core::time::Duration) {
+        #[cfg(feature = "std")]
+        if let Some(started_at) = self.started_at {
+            let elapsed = started_at.elapsed();
+            self.elapsed = Some(elapsed);
+            self.calc_intervals(elapsed, self.interval.unwrap_or(interval).as_nanos());
+        }
+        #[cfg(not(feature = "std"))]
+        let _ = interval;
+    }
+
+    /// Set the index to the number of intervals (in nanoseconds) in the elapsed time.
+    fn calc_intervals(&mut self, elapsed: core::time::Duration, interval: u128) {
+        let intervals = elapsed.as_nanos() / interval.max(1);
+        self.index = (intervals % Self::INDEX_PERIOD as u128) as i64;
+    }
+
+    /// Calculate the index from the elapsed time if time-driven.
+    fn calc_time_driven(&mut self, throbber: &Throbber) {
+        #[cfg(feature = "std")]
+        if let Some(started_at) = self.started_at {
+            self.calc_elapsed(started_at.elapsed(), throbber);
+        }
+        #[cfg(not(feature = "std"))]
+        let _ = throbber;
+    }
+
     /// Increase index.
     ///
     /// # Examples:
@@ -37,6 +333,9 @@ impl ThrobberState {
     ///
     /// If step is 0, the index is determined at random.
     ///
+    /// The index wraps around at `ThrobberState::INDEX_PERIOD`,
+    /// so the symbols keep cycling without a jump.
+    ///
     /// # Examples:
     /// ```
     /// let mut throbber_state = throbber_widgets_tui::ThrobberState::default();
@@ -45,15 +344,17 @@ impl ThrobberState {
     /// assert_eq!(throbber_state.index(), 2);
     /// throbber_state.calc_step(-3);
     /// assert_eq!(throbber_state.index(), -1);
-    /// throbber_state.calc_step(0); // random
-    /// assert!((std::i8::MIN..=std::i8::MAX).contains(&throbber_state.index()))
+    /// throbber_state.calc_step(0); // random, or unchanged without std and rand
+    /// let period = throbber_widgets_tui::ThrobberState::INDEX_PERIOD;
+    /// assert!((-period..period).contains(&throbber_state.index()))
     /// ```
-    pub fn calc_step(&mut self, step: i8) {
+    pub fn calc_step(&mut self, step: impl Into<i64>) {
+        let step: i64 = step.into();
         self.index = if step == 0 {
             #[cfg(feature = "rand")]
             {
                 let mut rng = rand::rng();
-                rng.random()
+                rng.random_range(0..Self::INDEX_PERIOD)
             }
             #[cfg(all(not(feature = "rand"), feature = "std"))]
             {
@@ -63,7 +364,7 @@ impl ThrobberState {
                 // If the resolution is low, it might become zero, so I add multiple values.
                 (duration.as_nanos() % 0x100
                     + duration.as_micros() % 0x100
-                    + duration.as_millis() % 0x100) as i8
+                    + duration.as_millis() % 0x100) as i64
             }
             #[cfg(all(not(feature = "rand"), not(feature = "std")))]
             {
@@ -72,19 +373,21 @@ impl ThrobberState {
                 self.index
             }
         } else {
-            self.index.checked_add(step).unwrap_or(0)
+            ((self.index as i128 + step as i128) % Self::INDEX_PERIOD as i128) as i64
         }
     }
 
     /// Set the index to the range of throbber_set.symbols.len().
     ///
-    /// This is called from render function automatically.
+    /// Render functions do not call this. They reduce the index to the common period
+    /// of the symbols, the palette and the label animations instead, so that all of them keep cycling.
+    /// Therefore `index()` after render can be len or more, and the rendered symbol is at `index() % len`.
     ///
     /// # Examples:
     /// ```
     /// let mut throbber_state = throbber_widgets_tui::ThrobberState::default();
     /// let throbber = throbber_widgets_tui::Throbber::default();
-    /// let len = 6; //throbber.throbber_set.symbols.len() as i8;
+    /// let len = 6; //throbber.throbber_set.symbols.len() as i64;
     ///
     /// throbber_state.normalize(&throbber);
     /// assert_eq!(throbber_state.index(), 0);
@@ -101,7 +404,7 @@ impl ThrobberState {
     /// assert_eq!(throbber_state.index(), len - 1);
     /// ```
     pub fn normalize(&mut self, throbber: &Throbber) {
-        let len = throbber.throbber_set.symbols.len() as i8;
+        let len = throbber.throbber_set.symbols.len() as i64;
         if len <= 0 {
             //ng but it's not used, so it stays.
         } else {
@@ -114,6 +417,20 @@ impl ThrobberState {
     }
 }
 
+/// Where the label is placed relative to the symbol.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum LabelPosition {
+    /// e.g. `Loading ⠷`
+    Left,
+    /// e.g. `⠷ Loading`
+    #[default]
+    Right,
+    /// The label is placed in the row above the symbol.
+    Above,
+    /// The label is placed in the row below the symbol.
+    Below,
+}
+
 /// A compact widget to display a throbber.
 ///
 /// A throbber may also be called:
@@ -135,11 +452,27 @@ impl ThrobberState {
 /// ```
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Throbber<'a> {
-    label: Option<ratatui::text::Span<'a>>,
-    style: ratatui::style::Style,
-    throbber_style: ratatui::style::Style,
-    throbber_set: crate::symbols::throbber::Set,
-    use_type: crate::symbols::throbber::WhichUse,
+    pub(crate) label: Option<ratatui::text::Text<'a>>,
+    pub(crate) style: ratatui::style::Style,
+    pub(crate) throbber_style: ratatui::style::Style,
+    pub(crate) throbber_set: crate::symbols::throbber::Set,
+    pub(crate) use_type: crate::symbols::throbber::WhichUse,
+    pub(crate) elapsed_format: Option<super::ElapsedFormat>,
+    pub(crate) bar_width: Option<u16>,
+    pub(crate) progress_display: super::ProgressDisplay,
+    pub(crate) palette: Option<super::Palette>,
+    pub(crate) label_effect: super::LabelEffect,
+    pub(crate) label_suffix: Option<super::LabelSuffix>,
+    pub(crate) label_position: LabelPosition,
+    pub(crate) alignment: Option<ratatui::layout::HorizontalAlignment>,
+    pub(crate) vertical_alignment: Option<ratatui::layout::VerticalAlignment>,
+    pub(crate) separator: ratatui::text::Span<'a>,
+    pub(crate) padding_left: u16,
+    pub(crate) padding_right: u16,
+    pub(crate) label_truncation: super::LabelTruncation,
+    pub(crate) label_min_width: u16,
+    pub(crate) label_wrap: bool,
+    pub(crate) block: Option<ratatui::widgets::Block<'a>>,
 }
 
 impl Default for Throbber<'_> {
@@ -150,14 +483,50 @@ impl Default for Throbber<'_> {
             throbber_style: ratatui::style::Style::default(),
             throbber_set: crate::symbols::throbber::BRAILLE_SIX,
             use_type: crate::symbols::throbber::WhichUse::Spin,
+            elapsed_format: None,
+            bar_width: None,
+            progress_display: super::ProgressDisplay::Percent,
+            palette: None,
+            label_effect: super::LabelEffect::None,
+            label_suffix: None,
+            label_position: LabelPosition::Right,
+            alignment: None,
+            vertical_alignment: None,
+            separator: ratatui::text::Span::raw(" "),
+            padding_left: 0,
+            padding_right: 0,
+            label_truncation: super::LabelTruncation::Clip,
+            label_min_width: 0,
+            label_wrap: false,
+            block: None,
         }
     }
 }
 
 impl<'a> Throbber<'a> {
+    /// Label displayed with the symbol. `&str`, `Span`, `Line` and `Text` can be used.
+    ///
+    /// Lines of the label are rendered in rows, and joined with a space by `to_line`.
+    ///
+    /// # Examples:
+    /// ```
+    /// use ratatui::style::Stylize;
+    /// use ratatui::text::Line;
+    /// use throbber_widgets_tui::{Throbber, ThrobberState};
+    /// let throbber = Throbber::default().label(Line::from(vec!["build".bold(), " 3/5".dim()]));
+    /// let throbber_state = ThrobberState::default();
+    /// assert_eq!(throbber.to_line(&throbber_state).to_string(), "⠷ build 3/5");
+    ///
+    /// let throbber = Throbber::default().label("build\n3/5");
+    /// let area = ratatui::layout::Rect::new(0, 0, 7, 2);
+    /// let mut buf = ratatui::buffer::Buffer::empty(area);
+    /// let mut throbber_state = ThrobberState::default();
+    /// ratatui::widgets::StatefulWidget::render(throbber, area, &mut buf, &mut throbber_state);
+    /// assert_eq!(buf, ratatui::buffer::Buffer::with_lines(["⠷ build", "  3/5  "]));
+    /// ```
     pub fn label<T>(mut self, label: T) -> Self
     where
-        T: Into<ratatui::text::Span<'a>>,
+        T: Into<ratatui::text::Text<'a>>,
     {
         self.label = Some(label.into());
         self
@@ -183,156 +552,946 @@ impl<'a> Throbber<'a> {
         self
     }
 
-    /// Convert symbol only to Span with state.
-    pub fn to_symbol_span(&self, state: &ThrobberState) -> ratatui::text::Span<'a> {
-        let symbol = match self.use_type {
-            crate::symbols::throbber::WhichUse::Full => self.throbber_set.full,
-            crate::symbols::throbber::WhichUse::Empty => self.throbber_set.empty,
-            crate::symbols::throbber::WhichUse::Spin => {
-                let mut state = state.clone();
-                state.normalize(self);
-                let len = self.throbber_set.symbols.len() as i8;
-                if 0 <= state.index && state.index < len {
-                    self.throbber_set.symbols[state.index as usize]
-                } else {
-                    self.throbber_set.empty
-                }
-            }
-        };
-        let mut symbol_text = String::from(symbol);
-        symbol_text.push(' ');
-        let symbol_span =
-            ratatui::text::Span::styled(symbol_text, self.style).patch_style(self.throbber_style);
-        symbol_span
+    /// Display the elapsed time of state with the format.
+    ///
+    /// Nothing is displayed while `ThrobberState::elapsed()` is None.
+    pub fn elapsed_format(mut self, format: super::ElapsedFormat) -> Self {
+        self.elapsed_format = Some(format);
+        self
     }
 
-    /// Convert symbol and label to Line with state.
-    pub fn to_line(&self, state: &ThrobberState) -> ratatui::text::Line<'a> {
-        let mut line = ratatui::text::Line::default().style(self.style);
-        line.spans.push(self.to_symbol_span(state));
-        if let Some(label) = &self.label.clone() {
-            line.spans.push(label.clone());
-        }
-        line
+    /// How the progress is displayed when `ThrobberState::total()` is known.
+    ///
+    /// Default is Percent.
+    ///
+    /// # Examples:
+    /// ```
+    /// use throbber_widgets_tui::{ProgressDisplay, Throbber, ThrobberState};
+    /// let throbber = Throbber::default().label("Downloading");
+    /// let mut throbber_state = ThrobberState::default();
+    /// assert_eq!(throbber.to_line(&throbber_state).to_string(), "⠷ Downloading");
+    ///
+    /// throbber_state.set_total(Some(200));
+    /// throbber_state.set_position(50);
+    /// assert_eq!(throbber.to_line(&throbber_state).to_string(), "⠷ 25% Downloading");
+    ///
+    /// let throbber = throbber.progress_display(ProgressDisplay::Bar(4));
+    /// assert_eq!(throbber.to_line(&throbber_state).to_string(), "⠷ █░░░ Downloading");
+    /// ```
+    pub fn progress_display(mut self, display: super::ProgressDisplay) -> Self {
+        self.progress_display = display;
+        self
     }
-}
 
-impl ratatui::widgets::Widget for Throbber<'_> {
-    /// Render random step symbols.
-    fn render(self, area: ratatui::layout::Rect, buf: &mut ratatui::buffer::Buffer) {
-        let mut state = ThrobberState::default();
-        state.calc_step(0);
-        ratatui::widgets::StatefulWidget::render(self, area, buf, &mut state);
+    /// Colors of the symbol cycling per frame.
+    ///
+    /// The color is patched to throbber_style. It is not applied to the outcome.
+    ///
+    /// # Examples:
+    /// ```
+    /// use throbber_widgets_tui::{ColorMode, Palette, Throbber, ThrobberState};
+    /// let throbber = Throbber::default().palette(Palette::rainbow(12).color_mode(ColorMode::Indexed));
+    /// let mut throbber_state = ThrobberState::default();
+    /// throbber_state.calc_step(6);
+    /// let span = throbber.to_symbol_span(&throbber_state);
+    /// assert_eq!(span.content, "⠷ ");
+    /// assert_eq!(span.style.fg, Some(ratatui::style::Color::Indexed(51)));
+    /// ```
+    pub fn palette(mut self, palette: super::Palette) -> Self {
+        self.palette = Some(palette);
+        self
     }
-}
 
-impl ratatui::widgets::StatefulWidget for Throbber<'_> {
-    type State = ThrobberState;
+    /// Animation of the label driven by the index of ThrobberState.
+    ///
+    /// See LabelEffect.
+    pub fn label_effect(mut self, label_effect: super::LabelEffect) -> Self {
+        self.label_effect = label_effect;
+        self
+    }
 
-    /// Render specified index symbols.
-    fn render(
-        self,
-        area: ratatui::layout::Rect,
-        buf: &mut ratatui::buffer::Buffer,
-        state: &mut Self::State,
-    ) {
-        buf.set_style(area, self.style);
+    /// Cycling suffix appended to the label, like growing dots.
+    ///
+    /// See LabelSuffix.
+    pub fn label_suffix(mut self, label_suffix: super::LabelSuffix) -> Self {
+        self.label_suffix = Some(label_suffix);
+        self
+    }
 
-        let throbber_area = area;
-        if throbber_area.height < 1 {
-            return;
-        }
+    /// Where the label is placed relative to the symbol.
+    pub fn label_position(mut self, position: LabelPosition) -> Self {
+        self.label_position = position;
+        self
+    }
 
-        // render a symbol.
-        let symbol = match self.use_type {
-            crate::symbols::throbber::WhichUse::Full => self.throbber_set.full,
-            crate::symbols::throbber::WhichUse::Empty => self.throbber_set.empty,
-            crate::symbols::throbber::WhichUse::Spin => {
-                state.normalize(&self);
-                let len = self.throbber_set.symbols.len() as i8;
-                if 0 <= state.index && state.index < len {
-                    self.throbber_set.symbols[state.index as usize]
-                } else {
-                    self.throbber_set.empty
-                }
-            }
-        };
-        let mut symbol_text = String::from(symbol);
-        symbol_text.push(' ');
-        let symbol_span = ratatui::text::Span::styled(symbol_text, self.throbber_style);
-        let (col, row) = buf.set_span(
-            throbber_area.left(),
-            throbber_area.top(),
-            &symbol_span,
-            symbol_span.width() as u16,
-        );
+    /// Horizontal alignment of the symbol and the label within the area.
+    ///
+    /// If not specified, multi-line symbols are centered and the others are aligned to the left.
+    pub fn alignment(mut self, alignment: ratatui::layout::HorizontalAlignment) -> Self {
+        self.alignment = Some(alignment);
+        self
+    }
 
-        // render a label.
-        if let Some(label) = self.label {
-            if throbber_area.right() <= col {
-                return;
-            }
-            buf.set_span(col, row, &label, label.width() as u16);
-        }
+    /// Vertical alignment of the symbol and the label within the area.
+    ///
+    /// If not specified, multi-line symbols are centered and the others are aligned to the top.
+    pub fn vertical_alignment(mut self, alignment: ratatui::layout::VerticalAlignment) -> Self {
+        self.vertical_alignment = Some(alignment);
+        self
     }
-}
 
-/// Convert symbol only to Span without state(mostly random index).
-///
-/// If you want to specify a state, use `Throbber::to_symbol_span()`.
-impl<'a> From<Throbber<'a>> for ratatui::text::Span<'a> {
-    fn from(throbber: Throbber<'a>) -> ratatui::text::Span<'a> {
-        let mut state = ThrobberState::default();
-        state.calc_step(0);
-        throbber.to_symbol_span(&state)
+    /// Separator between the symbol and the label. Default is a space, and it can be empty.
+    ///
+    /// It is always included after the symbol, also in `to_symbol_span`.
+    /// Where it has a span of its own (`to_line` and render), its style is kept.
+    /// `to_symbol_span` is a single Span, so there it has the style of the symbol.
+    ///
+    /// # Examples:
+    /// ```
+    /// use ratatui::style::Stylize;
+    /// use throbber_widgets_tui::{Throbber, ThrobberState};
+    /// let throbber_state = ThrobberState::default();
+    /// let throbber = Throbber::default().label("Loading").separator(": ");
+    /// assert_eq!(throbber.to_line(&throbber_state).to_string(), "⠷: Loading");
+    /// assert_eq!(throbber.to_symbol_span(&throbber_state).content, "⠷: ");
+    ///
+    /// let throbber = throbber.separator(" | ".dark_gray());
+    /// let line = throbber.to_line(&throbber_state);
+    /// assert_eq!(line.to_string(), "⠷ | Loading");
+    /// assert_eq!(line.spans[1].style.fg, Some(ratatui::style::Color::DarkGray));
+    /// assert_eq!(throbber.to_symbol_span(&throbber_state).content, "⠷ | ");
+    /// ```
+    pub fn separator<T>(mut self, separator: T) -> Self
+    where
+        T: Into<ratatui::text::Span<'a>>,
+    {
+        self.separator = separator.into();
+        self
     }
-}
 
-/// Convert symbol and label to Line without state(mostly random index).
-///
-/// If you want to specify a state, use `Throbber::to_line()`.
-impl<'a> From<Throbber<'a>> for ratatui::text::Line<'a> {
-    fn from(throbber: Throbber<'a>) -> ratatui::text::Line<'a> {
-        let mut state = ThrobberState::default();
-        state.calc_step(0);
-        throbber.to_line(&state)
+    /// Number of blank cells on the left and the right of the symbol and the label.
+    ///
+    /// The padding is included in both `to_line` and `to_symbol_span`.
+    /// In `to_line` it has the style of `style`.
+    /// `to_symbol_span` is a single Span, so there it has the style of the symbol.
+    ///
+    /// # Examples:
+    /// ```
+    /// use throbber_widgets_tui::{Throbber, ThrobberState};
+    /// let throbber = Throbber::default()
+    ///     .label("Loading")
+    ///     .style(ratatui::style::Style::default().bg(ratatui::style::Color::Blue))
+    ///     .padding(1, 2);
+    /// let throbber_state = ThrobberState::default();
+    /// let line = throbber.to_line(&throbber_state);
+    /// assert_eq!(line.to_string(), " ⠷ Loading  ");
+    /// assert_eq!(line.spans[0].style.bg, Some(ratatui::style::Color::Blue));
+    /// assert_eq!(throbber.to_symbol_span(&throbber_state).content, " ⠷   ");
+    /// ```
+    pub fn padding(mut self, left: u16, right: u16) -> Self {
+        self.padding_left = left;
+        self.padding_right = right;
+        self
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    #[test]
-    #[allow(unused_variables, unused_assignments)]
-    fn throbber_state_calc_step() {
-        let mut throbber_state = ThrobberState::default();
-        assert_eq!(throbber_state.index(), 0);
+    /// How the label is shortened when it does not fit in the area.
+    pub fn label_truncation(mut self, truncation: super::LabelTruncation) -> Self {
+        self.label_truncation = truncation;
+        self
+    }
 
-        // random test
-        // The probability of failure is not zero, but it is as low as possible.
-        let mut difference = false;
-        for _ in 0..100 {
-            throbber_state.calc_step(0);
-            assert!((i8::MIN..=i8::MAX).contains(&throbber_state.index()));
+    /// Hide the label if the width left for it in the area is less than width.
+    pub fn label_min_width(mut self, width: u16) -> Self {
+        self.label_min_width = width;
+        self
+    }
 
-            if 0 != throbber_state.index() {
-                difference = true;
-            }
-        }
-        // In no_std without rand, calc_step(0) keeps index unchanged, so no difference expected
-        #[cfg(any(feature = "rand", feature = "std"))]
-        assert!(difference);
+    /// Wrap the label at whitespace to the width of the area.
+    ///
+    /// The wrapped rows are truncated by label_truncation if a word still does not fit.
+    pub fn label_wrap(mut self, wrap: bool) -> Self {
+        self.label_wrap = wrap;
+        self
     }
 
-    #[test]
-    fn throbber_state_normalize() {
-        let mut throbber_state = ThrobberState::default();
-        let throbber = Throbber::default();
-        let len = throbber.throbber_set.symbols.len() as i8;
-        let max = len - 1;
+    /// Block around the throbber. The throbber is rendered in the inner area of the block.
+    pub fn block(mut self, block: ratatui::widgets::Block<'a>) -> Self {
+        self.block = Some(block);
+        self
+    }
 
-        // check upper
-        throbber_state.calc_step(max);
+    /// Put the symbol and the label with state into the title of block.
+    ///
+    /// The title is aligned by `alignment` if specified.
+    ///
+    /// # Examples:
+    /// ```
+    /// use ratatui::widgets::Block;
+    /// use throbber_widgets_tui::{Throbber, ThrobberState};
+    /// let throbber = Throbber::default().label("Loading").padding(1, 1);
+    /// let throbber_state = ThrobberState::default();
+    /// let block = throbber.titled_block(Block::bordered(), &throbber_state);
+    /// let area = ratatui::layout::Rect::new(0, 0, 14, 3);
+    /// let mut buf = ratatui::buffer::Buffer::empty(area);
+    /// ratatui::widgets::Widget::render(block, area, &mut buf);
+    /// assert_eq!(
+    ///     buf,
+    ///     ratatui::buffer::Buffer::with_lines([
+    ///         "┌ ⠷ Loading ─┐",
+    ///         "│            │",
+    ///         "└────────────┘",
+    ///     ])
+    /// );
+    /// ```
+    pub fn titled_block(
+        &self,
+        block: ratatui::widgets::Block<'a>,
+        state: &ThrobberState,
+    ) -> ratatui::widgets::Block<'a> {
+        block.title(self.to_line(state))
+    }
+
+    /// Width of the moving block in cells for Bounce and Sweep.
+    ///
+    /// If not specified, a quarter of the bar width is used.
+    pub fn bar_width(mut self, width: u16) -> Self {
+        self.bar_width = Some(width);
+        self
+    }
+
+    /// Whether use_type is Bounce or Sweep.
+    fn is_bar(&self) -> bool {
+        matches!(
+            self.use_type,
+            crate::symbols::throbber::WhichUse::Bounce | crate::symbols::throbber::WhichUse::Sweep
+        )
+    }
+
+    /// Get the symbol and its style to be rendered with state.
+    ///
+    /// The outcome of state takes precedence over use_type.
+    pub(crate) fn symbol(&self, state: &mut ThrobberState) -> (&str, ratatui::style::Style) {
+        let symbol = match (state.outcome, &self.use_type) {
+            (Some(outcome), _) | (None, &crate::symbols::throbber::WhichUse::Outcome(outcome)) => {
+                let outcome = self.throbber_set.outcomes.get(outcome);
+                return (&outcome.symbol, self.throbber_style.patch(outcome.style));
+            }
+            (None, crate::symbols::throbber::WhichUse::Full) => &self.throbber_set.full,
+            (None, crate::symbols::throbber::WhichUse::Empty) => &self.throbber_set.empty,
+            (
+                None,
+                crate::symbols::throbber::WhichUse::Spin
+                | crate::symbols::throbber::WhichUse::Bounce
+                | crate::symbols::throbber::WhichUse::Sweep,
+            ) => {
+                state.calc_time_driven(self);
+                let len = self.throbber_set.symbols.len() as i64;
+                let period = self.period();
+                if 0 < period {
+                    state.index = state.index.rem_euclid(period);
+                }
+                if 0 <= state.index && 0 < len {
+                    &self.throbber_set.symbols[(state.index % len) as usize]
+                } else {
+                    &self.throbber_set.empty
+                }
+            }
+        };
+        (symbol, self.frame_style(state.index))
+    }
+
+    /// Get the number of frames until the symbols, the palette and the label animations repeat.
+    ///
+    /// The index is reduced to this range at render time. 0 if there are no symbols.
+    fn period(&self) -> i64 {
+        let len = self.throbber_set.symbols.len() as i64;
+        let palette = self
+            .palette
+            .as_ref()
+            .map_or(0, |palette| palette.len() as i64);
+        let label_effect = self
+            .label
+            .as_ref()
+            .map_or(0, |_| self.label_effect.period(self.label_len()));
+        let label_suffix = self
+            .label_suffix
+            .as_ref()
+            .map_or(0, super::LabelSuffix::period);
+        [palette, label_effect, label_suffix]
+            .into_iter()
+            .filter(|&period| 0 < period && 0 < len)
+            .fold(len, lcm)
+    }
+
+    /// Get throbber_style patched with the color of the palette at index.
+    fn frame_style(&self, index: i64) -> ratatui::style::Style {
+        match self
+            .palette
+            .as_ref()
+            .and_then(|palette| palette.color(index))
+        {
+            Some(color) => self.throbber_style.fg(color),
+            None => self.throbber_style,
+        }
+    }
+
+    /// Get the symbol padded to the width of throbber_set, and its style.
+    ///
+    /// Multi-line symbols are represented by their first row to fit in a line.
+    pub(crate) fn padded_symbol(
+        &self,
+        state: &mut ThrobberState,
+    ) -> (String, ratatui::style::Style) {
+        let width = self.throbber_set.width();
+        let (symbol, throbber_style) = self.symbol(state);
+        let symbol = symbol.split('\n').next().unwrap_or_default();
+        let mut text = String::from(symbol);
+        let padding = width.saturating_sub(crate::symbols::throbber::display_width(symbol));
+        text.extend(core::iter::repeat_n(' ', padding));
+        (text, throbber_style)
+    }
+
+    /// Get the padded symbol joined with the separator as Span.
+    fn symbol_span(&self, state: &mut ThrobberState) -> ratatui::text::Span<'a> {
+        let mut symbol_span = self.bare_symbol_span(state);
+        symbol_span
+            .content
+            .to_mut()
+            .push_str(&self.separator.content);
+        symbol_span
+    }
+
+    /// Whether the separator is joined to the symbol span.
+    ///
+    /// Only an unstyled separator is joined in Line, so a styled one keeps its own style.
+    fn joins_separator(&self) -> bool {
+        self.separator.style == ratatui::style::Style::default()
+    }
+
+    /// Add the blank spans of padding on both sides.
+    fn pad(&self, spans: &mut Vec<ratatui::text::Span<'a>>) {
+        if 0 < self.padding_left {
+            let text: String = core::iter::repeat_n(' ', self.padding_left as usize).collect();
+            spans.insert(0, ratatui::text::Span::styled(text, self.style));
+        }
+        if 0 < self.padding_right {
+            let text: String = core::iter::repeat_n(' ', self.padding_right as usize).collect();
+            spans.push(ratatui::text::Span::styled(text, self.style));
+        }
+    }
+
+    /// Convert the elapsed time of state to Span.
+    fn elapsed_span(&self, state: &ThrobberState) -> Option<ratatui::text::Span<'a>> {
+        let format = self.elapsed_format.as_ref()?;
+        let mut text = format.format(state.elapsed()?);
+        if self.label.is_some() {
+            match format.position {
+                super::ElapsedPosition::BeforeLabel => text.push(' '),
+                super::ElapsedPosition::AfterLabel => text.insert(0, ' '),
+            }
+        }
+        Some(ratatui::text::Span::styled(text, format.style))
+    }
+
+    /// Fit the label rows in width by label_wrap, label_truncation and label_min_width.
+    fn fit_label(
+        &self,
+        label_rows: Vec<Vec<ratatui::text::Span<'a>>>,
+        width: usize,
+    ) -> Vec<Vec<ratatui::text::Span<'a>>> {
+        if width < self.label_min_width as usize {
+            return Vec::new();
+        }
+        label_rows
+            .into_iter()
+            .flat_map(|row| {
+                if self.label_wrap {
+                    super::truncation::wrap(row, width)
+                } else {
+                    alloc::vec![row]
+                }
+            })
+            .map(|row| self.label_truncation.truncate(row, width))
+            .collect()
+    }
+
+    /// Place the symbol and the label spans in a line by label_position.
+    fn symbol_line(
+        &self,
+        mut symbol_span: ratatui::text::Span<'a>,
+        label_spans: Vec<ratatui::text::Span<'a>>,
+    ) -> ratatui::text::Line<'a> {
+        let mut line = ratatui::text::Line::default().style(self.style);
+        line.alignment = self.alignment;
+        if self.label_position == LabelPosition::Left && !label_spans.is_empty() {
+            line.spans.extend(label_spans);
+            line.spans.push(self.separator.clone());
+            line.spans.push(symbol_span);
+        } else if self.joins_separator() {
+            symbol_span
+                .content
+                .to_mut()
+                .push_str(&self.separator.content);
+            line.spans.push(symbol_span);
+            line.spans.extend(label_spans);
+        } else {
+            line.spans.push(symbol_span);
+            line.spans.push(self.separator.clone());
+            line.spans.extend(label_spans);
+        }
+        line
+    }
+
+    /// Get the padded symbol as Span.
+    fn bare_symbol_span(&self, state: &mut ThrobberState) -> ratatui::text::Span<'a> {
+        let (symbol_text, throbber_style) = self.padded_symbol(state);
+        ratatui::text::Span::styled(symbol_text, self.style).patch_style(throbber_style)
+    }
+
+    /// Convert symbol and label to Line.
+    fn line(&self, state: &mut ThrobberState) -> ratatui::text::Line<'a> {
+        let symbol_span = self.bare_symbol_span(state);
+        self.symbol_line(symbol_span, self.label_spans(state))
+    }
+
+    /// Whether the label is placed in its own row, above or below the symbol.
+    fn is_label_stacked(&self) -> bool {
+        matches!(
+            self.label_position,
+            LabelPosition::Above | LabelPosition::Below
+        )
+    }
+
+    /// Get the symbol and the label in rows of width, placed by label_position.
+    fn lines(&self, state: &mut ThrobberState, width: u16) -> Vec<ratatui::text::Line<'a>> {
+        let width = width as usize;
+        let symbol_span = self.bare_symbol_span(state);
+        if !self.is_label_stacked() {
+            // The rows after the first are indented to the label in the first row.
+            let indent = symbol_span.width() + self.separator.width();
+            let label_rows = self.fit_label(self.label_rows(state), width.saturating_sub(indent));
+            let mut label_rows = label_rows.into_iter();
+            let first = self.symbol_line(symbol_span, label_rows.next().unwrap_or_default());
+            let indent = match self.label_position {
+                LabelPosition::Left => 0,
+                _ => indent,
+            };
+            let rest = label_rows.map(|mut row| {
+                if 0 < indent {
+                    let text: String = core::iter::repeat_n(' ', indent).collect();
+                    row.insert(0, ratatui::text::Span::raw(text));
+                }
+                let mut line = ratatui::text::Line::from(row).style(self.style);
+                line.alignment = self.alignment;
+                line
+            });
+            let mut lines: Vec<ratatui::text::Line> = core::iter::once(first).chain(rest).collect();
+            // Center or Right aligned rows stay under the first label row only if they are equally wide.
+            if !matches!(
+                self.alignment,
+                None | Some(ratatui::layout::HorizontalAlignment::Left)
+            ) {
+                let max_width = lines.iter().map(ratatui::text::Line::width).max();
+                for line in &mut lines {
+                    let padding = max_width.unwrap_or_default() - line.width();
+                    if 0 < padding {
+                        let text: String = core::iter::repeat_n(' ', padding).collect();
+                        line.spans.push(ratatui::text::Span::raw(text));
+                    }
+                }
+            }
+            return lines;
+        }
+        let alignment = self.alignment.unwrap_or_default();
+        let symbol_line = ratatui::text::Line::from(symbol_span).alignment(alignment);
+        let label_lines = self
+            .fit_label(self.label_rows(state), width)
+            .into_iter()
+            .map(|row| ratatui::text::Line::from(row).alignment(alignment));
+        if self.label_position == LabelPosition::Above {
+            label_lines.chain(core::iter::once(symbol_line)).collect()
+        } else {
+            core::iter::once(symbol_line).chain(label_lines).collect()
+        }
+    }
+
+    /// Get the number of graphemes of the label.
+    fn label_len(&self) -> usize {
+        self.label.as_ref().map_or(0, |label| {
+            label
+                .iter()
+                .flat_map(|line| &line.spans)
+                .map(grapheme_count)
+                .sum()
+        })
+    }
+
+    /// Get the lines of the label as Spans, with the styles of the text and the lines patched.
+    fn label_text_rows(&self) -> Vec<Vec<ratatui::text::Span<'a>>> {
+        let Some(label) = &self.label else {
+            return Vec::new();
+        };
+        label
+            .iter()
+            .map(|line| {
+                let style = label.style.patch(line.style);
+                line.spans
+                    .iter()
+                    .map(|span| span.clone().style(style.patch(span.style)))
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Get the label joined in a row with a space.
+    pub(crate) fn label_text_spans(&self) -> Vec<ratatui::text::Span<'a>> {
+        join_rows(self.label_text_rows())
+    }
+
+    /// Convert the progress, the label and the elapsed time to Spans in a row.
+    fn label_spans(&self, state: &ThrobberState) -> Vec<ratatui::text::Span<'a>> {
+        join_rows(self.label_rows(state))
+    }
+
+    /// Convert the progress, the label and the elapsed time to rows of Spans.
+    ///
+    /// The progress and the elapsed time are put in the first or the last row.
+    /// No rows if all are empty.
+    fn label_rows(&self, state: &ThrobberState) -> Vec<Vec<ratatui::text::Span<'a>>> {
+        let mut rows = self.label_text_rows();
+        if state.outcome.is_none() {
+            let len = self.label_len();
+            let mut offset = 0;
+            for row in &mut rows {
+                let mut spans = Vec::new();
+                for span in row.iter() {
+                    spans.extend(self.label_effect.spans(span, state.index, offset, len));
+                    offset += grapheme_count(span);
+                }
+                *row = spans;
+            }
+            if let Some(last) = rows.last_mut() {
+                last.extend(
+                    self.label_suffix
+                        .as_ref()
+                        .and_then(|suffix| suffix.span(state.index)),
+                );
+            }
+        }
+        if rows.is_empty() {
+            rows.push(Vec::new());
+        }
+
+        if let Some(elapsed) = self.elapsed_span(state) {
+            let elapsed_before_label = matches!(
+                self.elapsed_format,
+                Some(super::ElapsedFormat {
+                    position: super::ElapsedPosition::BeforeLabel,
+                    ..
+                })
+            );
+            if elapsed_before_label {
+                rows[0].insert(0, elapsed);
+            } else if let Some(last) = rows.last_mut() {
+                last.push(elapsed);
+            }
+        }
+        if let Some(mut progress) = state
+            .total
+            .and_then(|total| self.progress_display.format(state.position, total))
+        {
+            if !rows[0].is_empty() {
+                progress.push(' ');
+            }
+            rows[0].insert(
+                0,
+                ratatui::text::Span::styled(progress, self.throbber_style),
+            );
+        }
+        if rows.iter().all(Vec::is_empty) {
+            rows.clear();
+        }
+        rows
+    }
+
+    /// Render a block moving across the width, followed by the label.
+    ///
+    /// The rows of the label are placed beside the bar, or above or below it by label_position.
+    /// Returns false if the area is too narrow for the bar.
+    fn render_bar(
+        &self,
+        area: ratatui::layout::Rect,
+        buf: &mut ratatui::buffer::Buffer,
+        state: &mut ThrobberState,
+    ) -> bool {
+        let separator_width = self.separator.width() as u16;
+        let label_area_width = if self.is_label_stacked() {
+            area.width
+        } else {
+            area.width.saturating_sub(separator_width)
+        };
+        let label_rows = self.fit_label(self.label_rows(state), label_area_width as usize);
+        let label_width = label_rows
+            .iter()
+            .map(|row| row.iter().map(ratatui::text::Span::width).sum::<usize>())
+            .max()
+            .unwrap_or_default() as u16;
+        let stacked = self.is_label_stacked() && !label_rows.is_empty();
+        let cells = if label_rows.is_empty() || stacked {
+            area.width
+        } else {
+            area.width.saturating_sub(label_width + separator_width)
+        };
+        let symbols = &self.throbber_set.symbols;
+        // A cell of the bar can not hold wide symbols.
+        let is_wide = symbols
+            .iter()
+            .any(|symbol| 1 < crate::symbols::throbber::display_width(symbol));
+        if cells < MIN_BAR_WIDTH || symbols.is_empty() || is_wide {
+            return false;
+        }
+        state.calc_time_driven(self);
+
+        let label_height = label_rows.len() as u16;
+        let height = if stacked {
+            label_height + 1
+        } else {
+            label_height.max(1)
+        };
+        let top = aligned_top(area, height, self.vertical_alignment.unwrap_or_default());
+        let (bar_y, label_y) = match self.label_position {
+            LabelPosition::Above if stacked => (top + label_height, top),
+            LabelPosition::Below if stacked => (top, top + 1),
+            _ => (top, top),
+        };
+        let is_label_left = self.label_position == LabelPosition::Left && !label_rows.is_empty();
+        let bar_x = if is_label_left {
+            area.left() + label_width + separator_width
+        } else {
+            area.left()
+        };
+
+        // Positions are in sub-cells, and one cell has symbols.len() sub-cells.
+        let n = symbols.len() as i64;
+        let block = self.bar_width.unwrap_or(cells / 4).clamp(1, cells) as i64 * n;
+        let width = cells as i64 * n;
+        let (start, end) = match (state.total, &self.use_type) {
+            // A known total turns the bar into a gauge.
+            (Some(total), _) => (
+                0,
+                super::progress::scale(state.position, total, width as u64) as i64,
+            ),
+            (None, crate::symbols::throbber::WhichUse::Sweep) => {
+                let start = state.index.rem_euclid(width + block) - block;
+                (start, start + block)
+            }
+            (None, _) => {
+                let travel = width - block;
+                let pos = state.index.rem_euclid((2 * travel).max(1));
+                let start = if pos <= travel { pos } else { 2 * travel - pos };
+                (start, start + block)
+            }
+        };
+
+        let throbber_style = self.frame_style(state.index);
+        for (i, x) in (bar_x..bar_x + cells).enumerate() {
+            let (left, right) = (i as i64 * n, (i as i64 + 1) * n);
+            let covered = end.min(right) - start.max(left);
+            let (symbol, style) = if covered <= 0 {
+                (" ", throbber_style)
+            } else if covered == n {
+                (&*self.throbber_set.full, throbber_style)
+            } else if left < start {
+                // The block covers the right part, so draw the left part reversed.
+                (
+                    &*symbols[(n - covered - 1) as usize],
+                    throbber_style.add_modifier(ratatui::style::Modifier::REVERSED),
+                )
+            } else {
+                (&*symbols[(covered - 1) as usize], throbber_style)
+            };
+            if bar_y < area.bottom() {
+                buf[(x, bar_y)].set_symbol(symbol).set_style(style);
+            }
+        }
+
+        let label_ys = label_y..area.bottom();
+        if stacked {
+            for (y, row) in label_ys.zip(label_rows) {
+                let row_area = ratatui::layout::Rect::new(area.left(), y, area.width, 1);
+                let row =
+                    ratatui::text::Line::from(row).alignment(self.alignment.unwrap_or_default());
+                ratatui::widgets::Widget::render(row, row_area, buf);
+            }
+        } else if !label_rows.is_empty() {
+            // The rows after the first are placed under the first row, next to the bar.
+            let (label_x, separator_x) = if is_label_left {
+                (area.left(), area.left() + label_width)
+            } else {
+                (area.left() + cells + separator_width, area.left() + cells)
+            };
+            if label_y < area.bottom() {
+                buf.set_span(separator_x, label_y, &self.separator, separator_width);
+            }
+            for (y, row) in label_ys.zip(label_rows) {
+                let row = ratatui::text::Line::from(row);
+                buf.set_line(label_x, y, &row, area.right().saturating_sub(label_x));
+            }
+        }
+        true
+    }
+
+    /// Render multi-line symbols at the center of area, with the label below.
+    ///
+    /// The label is placed above if label_position is Above, otherwise below.
+    /// Rows that do not fit in area are not rendered.
+    fn render_large(
+        &self,
+        area: ratatui::layout::Rect,
+        buf: &mut ratatui::buffer::Buffer,
+        state: &mut ThrobberState,
+    ) {
+        let width = self.throbber_set.width();
+        let alignment = self
+            .alignment
+            .unwrap_or(ratatui::layout::HorizontalAlignment::Center);
+        let (symbol, throbber_style) = self.symbol(state);
+        let mut lines: Vec<ratatui::text::Line> = symbol
+            .split('\n')
+            .map(|line| {
+                // Pad each line so that the lines are aligned at the center.
+                let mut text = String::from(line);
+                let padding = width.saturating_sub(crate::symbols::throbber::display_width(line));
+                text.extend(core::iter::repeat_n(' ', padding));
+                ratatui::text::Line::styled(text, throbber_style).alignment(alignment)
+            })
+            .collect();
+        // Pad shorter symbols like outcomes to the height of the set, centered vertically.
+        let missing = self.throbber_set.height().saturating_sub(lines.len());
+        let blank = || {
+            let text: String = core::iter::repeat_n(' ', width).collect();
+            ratatui::text::Line::styled(text, throbber_style).alignment(alignment)
+        };
+        lines.splice(0..0, core::iter::repeat_with(blank).take(missing / 2));
+        lines.extend(core::iter::repeat_with(blank).take(missing - missing / 2));
+        let label_lines = self
+            .fit_label(self.label_rows(state), area.width as usize)
+            .into_iter()
+            .map(|row| ratatui::text::Line::from(row).alignment(alignment));
+        if self.label_position == LabelPosition::Above {
+            lines.splice(0..0, label_lines);
+        } else {
+            lines.extend(label_lines);
+        }
+
+        let vertical_alignment = self
+            .vertical_alignment
+            .unwrap_or(ratatui::layout::VerticalAlignment::Center);
+        render_rows(lines, area, buf, vertical_alignment);
+    }
+
+    /// Convert symbol only to Span with state.
+    ///
+    /// It has the same text as `to_line` without the label: the padding, the symbol and the separator.
+    /// A Span has a single style, so all of them have the style of the symbol.
+    /// Use `to_line()` to keep the styles of the separator and the padding.
+    /// Multi-line symbols like LARGE_CLOCK are represented by their first row.
+    pub fn to_symbol_span(&self, state: &ThrobberState) -> ratatui::text::Span<'a> {
+        let mut symbol_span = self.symbol_span(&mut state.clone());
+        if 0 < self.padding_left || 0 < self.padding_right {
+            let mut text: String = core::iter::repeat_n(' ', self.padding_left as usize).collect();
+            text.push_str(&symbol_span.content);
+            text.extend(core::iter::repeat_n(' ', self.padding_right as usize));
+            symbol_span.content = text.into();
+        }
+        symbol_span
+    }
+
+    /// Convert symbol and label to Line with state.
+    ///
+    /// The elapsed time is also included if `elapsed_format` is specified.
+    /// The label is placed after the symbol if label_position is Above or Below.
+    /// Multi-line symbols like LARGE_CLOCK are represented by their first row.
+    pub fn to_line(&self, state: &ThrobberState) -> ratatui::text::Line<'a> {
+        let mut line = self.line(&mut state.clone());
+        self.pad(&mut line.spans);
+        line
+    }
+}
+
+impl ratatui::widgets::Widget for Throbber<'_> {
+    /// Render random step symbols.
+    fn render(self, area: ratatui::layout::Rect, buf: &mut ratatui::buffer::Buffer) {
+        let mut state = ThrobberState::default();
+        state.calc_step(0);
+        ratatui::widgets::StatefulWidget::render(self, area, buf, &mut state);
+    }
+}
+
+impl ratatui::widgets::StatefulWidget for Throbber<'_> {
+    type State = ThrobberState;
+
+    /// Render specified index symbols.
+    fn render(
+        self,
+        area: ratatui::layout::Rect,
+        buf: &mut ratatui::buffer::Buffer,
+        state: &mut Self::State,
+    ) {
+        buf.set_style(area, self.style);
+        let area = match &self.block {
+            Some(block) => {
+                ratatui::widgets::Widget::render(block, area, buf);
+                block.inner(area)
+            }
+            None => area,
+        };
+
+        let padding_left = self.padding_left.min(area.width);
+        let throbber_area = ratatui::layout::Rect {
+            x: area.x + padding_left,
+            width: (area.width - padding_left).saturating_sub(self.padding_right),
+            ..area
+        };
+        if throbber_area.height < 1 {
+            return;
+        }
+
+        // render multi-line symbols at the center.
+        if 1 < self.throbber_set.height() {
+            self.render_large(throbber_area, buf, state);
+            return;
+        }
+
+        // render a bar instead of a symbol if possible.
+        if state.outcome.is_none() && self.is_bar() && self.render_bar(throbber_area, buf, state) {
+            return;
+        }
+
+        // render a symbol, a label and an elapsed time.
+        let lines = self.lines(state, throbber_area.width);
+        render_rows(
+            lines,
+            throbber_area,
+            buf,
+            self.vertical_alignment.unwrap_or_default(),
+        );
+    }
+}
+
+/// Join rows of Spans in a row with a space.
+fn join_rows<'a>(rows: Vec<Vec<ratatui::text::Span<'a>>>) -> Vec<ratatui::text::Span<'a>> {
+    let mut spans = Vec::new();
+    for (i, row) in rows.into_iter().enumerate() {
+        if 0 < i {
+            spans.push(ratatui::text::Span::raw(" "));
+        }
+        spans.extend(row);
+    }
+    spans
+}
+
+/// Get the number of graphemes in span.
+fn grapheme_count(span: &ratatui::text::Span) -> usize {
+    span.styled_graphemes(ratatui::style::Style::default())
+        .count()
+}
+
+/// Get the top of height rows aligned vertically in area.
+fn aligned_top(
+    area: ratatui::layout::Rect,
+    height: u16,
+    alignment: ratatui::layout::VerticalAlignment,
+) -> u16 {
+    let space = area.height.saturating_sub(height);
+    area.top()
+        + match alignment {
+            ratatui::layout::VerticalAlignment::Top => 0,
+            ratatui::layout::VerticalAlignment::Center => space / 2,
+            ratatui::layout::VerticalAlignment::Bottom => space,
+        }
+}
+
+/// Render lines in consecutive rows aligned vertically in area.
+///
+/// Rows that do not fit in area are not rendered.
+fn render_rows(
+    lines: Vec<ratatui::text::Line>,
+    area: ratatui::layout::Rect,
+    buf: &mut ratatui::buffer::Buffer,
+    alignment: ratatui::layout::VerticalAlignment,
+) {
+    let height = (lines.len() as u16).min(area.height);
+    let top = aligned_top(area, height, alignment);
+    for (y, line) in (top..top + height).zip(lines) {
+        let row = ratatui::layout::Rect::new(area.left(), y, area.width, 1);
+        ratatui::widgets::Widget::render(line, row, buf);
+    }
+}
+
+/// Convert symbol only to Span without state(mostly random index).
+///
+/// If you want to specify a state, use `Throbber::to_symbol_span()`.
+impl<'a> From<Throbber<'a>> for ratatui::text::Span<'a> {
+    fn from(throbber: Throbber<'a>) -> ratatui::text::Span<'a> {
+        let mut state = ThrobberState::default();
+        state.calc_step(0);
+        throbber.to_symbol_span(&state)
+    }
+}
+
+/// Convert symbol and label to Line without state(mostly random index).
+///
+/// If you want to specify a state, use `Throbber::to_line()`.
+impl<'a> From<Throbber<'a>> for ratatui::text::Line<'a> {
+    fn from(throbber: Throbber<'a>) -> ratatui::text::Line<'a> {
+        let mut state = ThrobberState::default();
+        state.calc_step(0);
+        throbber.to_line(&state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+    #[test]
+    #[allow(unused_variables, unused_assignments)]
+    fn throbber_state_calc_step() {
+        let mut throbber_state = ThrobberState::default();
+        assert_eq!(throbber_state.index(), 0);
+
+        // random test
+        // The probability of failure is not zero, but it is as low as possible.
+        let mut difference = false;
+        for _ in 0..100 {
+            throbber_state.calc_step(0);
+            assert!((0..ThrobberState::INDEX_PERIOD).contains(&throbber_state.index()));
+
+            if 0 != throbber_state.index() {
+                difference = true;
+            }
+        }
+        // In no_std without rand, calc_step(0) keeps index unchanged, so no difference expected
+        #[cfg(any(feature = "rand", feature = "std"))]
+        assert!(difference);
+    }
+
+    #[test]
+    fn lcm_saturates() {
+        assert_eq!(lcm(4, 6), 12);
+        assert_eq!(lcm(i64::MAX - 1, i64::MAX), i64::MAX);
+    }
+
+    #[test]
+    fn throbber_state_normalize() {
+        let mut throbber_state = ThrobberState::default();
+        let throbber = Throbber::default();
+        let len = throbber.throbber_set.symbols.len() as i64;
+        let max = len - 1;
+
+        // check upper
+        throbber_state.calc_step(max);
         throbber_state.normalize(&throbber);
         assert_eq!(throbber_state.index(), max);
 
@@ -352,6 +1511,650 @@ mod tests {
         assert_eq!(throbber_state.index(), max);
     }
 
+    #[test]
+    fn throbber_state_calc_elapsed() {
+        let mut throbber_state = ThrobberState::default();
+        throbber_state.set_interval(Some(core::time::Duration::from_millis(100)));
+        let throbber = Throbber::default();
+        let len = throbber.throbber_set.symbols.len() as i64;
+
+        throbber_state.calc_elapsed(core::time::Duration::from_millis(99), &throbber);
+        assert_eq!(throbber_state.index(), 0);
+        throbber_state.calc_elapsed(core::time::Duration::from_millis(100), &throbber);
+        assert_eq!(throbber_state.index(), 1);
+
+        // Large elapsed time does not overflow.
+        throbber_state.calc_elapsed(core::time::Duration::from_secs(3600 * 24), &throbber);
+        throbber_state.normalize(&throbber);
+        assert!((0..len).contains(&throbber_state.index()));
+
+        // Zero interval is treated as the minimum resolution.
+        throbber_state.set_interval(Some(core::time::Duration::ZERO));
+        throbber_state.calc_elapsed(core::time::Duration::from_nanos(1), &throbber);
+        assert_eq!(throbber_state.index(), 1);
+    }
+
+    #[test]
+    fn throbber_state_calc_elapsed_with_set_durations() {
+        use crate::symbols::throbber::VERTICAL_BLOCK;
+        let mut throbber_state = ThrobberState::default();
+        let throbber = Throbber::default().throbber_set(VERTICAL_BLOCK);
+        let last = VERTICAL_BLOCK.symbols.len() - 1;
+        let hold_start = VERTICAL_BLOCK.interval * last as u32;
+
+        throbber_state.calc_elapsed(VERTICAL_BLOCK.interval, &throbber);
+        assert_eq!(throbber_state.index(), 1);
+
+        // The last symbol is held longer than the others.
+        throbber_state.calc_elapsed(hold_start, &throbber);
+        assert_eq!(throbber_state.index(), last as i64);
+        throbber_state.calc_elapsed(hold_start + VERTICAL_BLOCK.interval, &throbber);
+        assert_eq!(throbber_state.index(), last as i64);
+
+        // Next cycle.
+        throbber_state.calc_elapsed(VERTICAL_BLOCK.cycle_duration(), &throbber);
+        assert_eq!(throbber_state.index(), last as i64 + 1);
+        throbber_state.normalize(&throbber);
+        assert_eq!(throbber_state.index(), 0);
+
+        // The interval of state overrides the timing of set.
+        throbber_state.set_interval(Some(VERTICAL_BLOCK.interval));
+        throbber_state.calc_elapsed(hold_start + VERTICAL_BLOCK.interval, &throbber);
+        throbber_state.normalize(&throbber);
+        assert_eq!(throbber_state.index(), 0);
+    }
+
+    #[test]
+    fn builtin_sets_have_interval() {
+        for (_, set) in crate::symbols::throbber::BUILTIN_SETS {
+            assert!(!set.interval.is_zero());
+            assert!(set.durations.is_empty() || set.durations.len() == set.symbols.len());
+        }
+    }
+
+    #[test]
+    fn builtin_sets_have_consistent_width() {
+        use crate::symbols::throbber::display_width;
+        for (name, set) in crate::symbols::throbber::BUILTIN_SETS {
+            let width = set.width();
+            assert_eq!(display_width(&set.full), width, "{name}");
+            assert_eq!(display_width(&set.empty), width, "{name}");
+            for symbol in set.symbols.iter() {
+                assert_eq!(display_width(symbol), width, "{name}: {symbol:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn throbber_pads_symbol_to_set_width() {
+        let set = crate::symbols::throbber::Set::new("[===]", "", ["[=  ]", "=", "[ = ]"]);
+        let throbber = Throbber::default().throbber_set(set).label("x");
+        let mut throbber_state = ThrobberState::default();
+        let mut lines = Vec::new();
+        for _ in 0..3 {
+            lines.push(throbber.to_line(&throbber_state).to_string());
+            throbber_state.calc_next();
+        }
+        assert_eq!(lines, ["[=  ] x", "=     x", "[ = ] x"]);
+
+        let throbber = throbber.use_type(crate::symbols::throbber::WhichUse::Empty);
+        assert_eq!(throbber.to_line(&throbber_state).to_string(), "      x");
+    }
+
+    #[test]
+    fn throbber_reserves_outcome_width() {
+        let set = crate::symbols::throbber::Set {
+            outcomes: crate::symbols::throbber::OUTCOME_EMOJI,
+            ..crate::symbols::throbber::BRAILLE_SIX
+        };
+        let throbber = Throbber::default().throbber_set(set).label("x");
+        let mut throbber_state = ThrobberState::default();
+        assert_eq!(throbber.to_line(&throbber_state).to_string(), "⠷  x");
+        throbber_state.finish(crate::symbols::throbber::Outcome::Success);
+        assert_eq!(throbber.to_line(&throbber_state).to_string(), "✅ x");
+    }
+
+    #[test]
+    fn large_sets_have_consistent_size() {
+        use crate::symbols::throbber::display_width;
+        for (name, set) in crate::symbols::throbber::LARGE_SETS {
+            let (width, height) = (set.width(), set.height());
+            assert!(1 < height, "{name}");
+            for symbol in set.symbols.iter().chain([&set.full, &set.empty]) {
+                let lines: Vec<&str> = symbol.split('\n').collect();
+                assert_eq!(lines.len(), height, "{name}: {symbol:?}");
+                for line in lines {
+                    assert_eq!(display_width(line), width, "{name}: {line:?}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn large_set_to_line_uses_first_row() {
+        let throbber = Throbber::default()
+            .throbber_set(crate::symbols::throbber::LARGE_RING)
+            .label("Loading");
+        let mut throbber_state = ThrobberState::default();
+        throbber_state.calc_step(1);
+        assert_eq!(throbber.to_symbol_span(&throbber_state).content, " ○ ●  ");
+        assert_eq!(
+            throbber.to_line(&throbber_state).to_string(),
+            " ○ ●  Loading"
+        );
+
+        throbber_state.finish(crate::symbols::throbber::Outcome::Success);
+        assert_eq!(
+            throbber.to_line(&throbber_state).to_string(),
+            "✔     Loading"
+        );
+    }
+
+    #[test]
+    fn throbber_renders_large_set_at_center() {
+        let throbber = Throbber::default()
+            .throbber_set(crate::symbols::throbber::LARGE_RING)
+            .label("Loading");
+        let mut throbber_state = ThrobberState::default();
+        throbber_state.calc_step(2);
+
+        assert_eq!(
+            render(throbber, &mut throbber_state, 11, 6),
+            ratatui::buffer::Buffer::with_lines([
+                "           ",
+                "    ○ ○    ",
+                "   ○   ●   ",
+                "    ○ ○    ",
+                "  Loading  ",
+                "           ",
+            ])
+        );
+    }
+
+    #[test]
+    fn throbber_pads_large_outcome_to_set_size() {
+        let throbber = Throbber::default()
+            .throbber_set(crate::symbols::throbber::LARGE_RING)
+            .label("Loading");
+        let mut throbber_state = ThrobberState::default();
+        throbber_state.finish(crate::symbols::throbber::Outcome::Success);
+        assert_eq!(
+            crate::widgets::render_rows(throbber, &mut throbber_state, 6),
+            ["", "", "       ✔", "", "      Loading", ""]
+        );
+    }
+
+    #[test]
+    fn throbber_places_label_with_alignment() {
+        let throbber = Throbber::default()
+            .use_type(crate::symbols::throbber::WhichUse::Full)
+            .label("Load")
+            .label_position(LabelPosition::Left);
+        let throbber_state = ThrobberState::default();
+        assert_eq!(throbber.to_line(&throbber_state).to_string(), "Load ⠿");
+
+        let buf = render(
+            throbber
+                .clone()
+                .alignment(ratatui::layout::HorizontalAlignment::Right)
+                .vertical_alignment(ratatui::layout::VerticalAlignment::Bottom),
+            &mut ThrobberState::default(),
+            8,
+            3,
+        );
+        assert_eq!(
+            buf,
+            ratatui::buffer::Buffer::with_lines(["        ", "        ", "  Load ⠿"])
+        );
+        let buf = render(
+            throbber
+                .label_position(LabelPosition::Below)
+                .alignment(ratatui::layout::HorizontalAlignment::Center)
+                .vertical_alignment(ratatui::layout::VerticalAlignment::Center),
+            &mut ThrobberState::default(),
+            8,
+            3,
+        );
+        assert_eq!(
+            buf,
+            ratatui::buffer::Buffer::with_lines(["   ⠿    ", "  Load  ", "        "])
+        );
+    }
+
+    #[test]
+    fn throbber_cycles_palette_per_frame() {
+        use ratatui::style::Color;
+        let throbber = Throbber::default()
+            .throbber_style(ratatui::style::Style::default().bg(Color::Black))
+            .palette(crate::widgets::Palette::new([
+                Color::Red,
+                Color::Green,
+                Color::Blue,
+                Color::White,
+            ]));
+        let mut throbber_state = ThrobberState::default();
+        let mut frames = Vec::new();
+        for _ in 0..13 {
+            let (symbol, style) = throbber.symbol(&mut throbber_state);
+            assert_eq!(style.bg, Some(Color::Black));
+            frames.push((String::from(symbol), style.fg.unwrap()));
+            throbber_state.calc_next();
+        }
+        // 6 symbols and 4 colors cycle independently, so the 12th frame is the same as the first.
+        assert_eq!(frames[12], frames[0]);
+        assert_eq!(frames[6], (String::from("⠷"), Color::Blue));
+        assert_eq!(frames[4], (String::from("⠽"), Color::Red));
+        assert!(throbber_state.index() < 12);
+    }
+
+    #[test]
+    fn throbber_hides_empty_progress_bar() {
+        let throbber = Throbber::default()
+            .label("Downloading")
+            .progress_display(super::super::ProgressDisplay::Bar(0));
+        let mut throbber_state = ThrobberState::default();
+        throbber_state.set_total(Some(10));
+        throbber_state.set_position(4);
+        assert_eq!(
+            throbber.to_line(&throbber_state).to_string(),
+            "⠷ Downloading"
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn throbber_state_serde_round_trip() {
+        let mut throbber_state = ThrobberState::default();
+        throbber_state.calc_step(5);
+        throbber_state.set_total(Some(10));
+        throbber_state.set_position(4);
+        throbber_state.finish(crate::symbols::throbber::Outcome::Success);
+        let json = serde_json::to_string(&throbber_state).unwrap();
+        assert_eq!(
+            serde_json::from_str::<ThrobberState>(&json).unwrap(),
+            throbber_state
+        );
+        assert_eq!(
+            serde_json::from_str::<ThrobberState>(r#"{"index": 3}"#)
+                .unwrap()
+                .index(),
+            3
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn throbber_time_driven_render() {
+        let mut throbber_state = ThrobberState::default();
+        throbber_state.set_interval(Some(core::time::Duration::from_secs(3600)));
+        throbber_state.calc_step(3);
+        throbber_state.start();
+
+        // The elapsed time is less than one frame, so the first symbol is used.
+        let throbber = Throbber::default();
+        let buf = render(throbber, &mut throbber_state, 4, 1);
+        assert_eq!(throbber_state.index(), 0);
+        assert_eq!(buf[(0, 0)].symbol(), "⠷");
+
+        // Stopped state keeps the index and is driven manually again.
+        throbber_state.stop();
+        throbber_state.calc_next();
+        assert_eq!(throbber_state.index(), 1);
+    }
+
+    #[test]
+    fn throbber_renders_runtime_set() {
+        let frames: Vec<String> = ["a", "b", "c"].iter().map(|s| s.repeat(2)).collect();
+        let set = crate::symbols::throbber::Set::new(String::from("##"), "  ", frames);
+        let throbber = Throbber::default().throbber_set(set).label("x");
+        let mut throbber_state = ThrobberState::default();
+        throbber_state.calc_step(4);
+        assert_eq!(throbber.to_line(&throbber_state).to_string(), "bb x");
+
+        let throbber = throbber.use_type(crate::symbols::throbber::WhichUse::Full);
+        assert_eq!(throbber.to_line(&throbber_state).to_string(), "## x");
+    }
+
+    #[test]
+    fn throbber_renders_outcome() {
+        use crate::symbols::throbber::{Outcome, WhichUse};
+        let throbber = Throbber::default()
+            .label("done")
+            .throbber_style(ratatui::style::Style::default().bg(ratatui::style::Color::Black));
+        let mut throbber_state = ThrobberState::default();
+        throbber_state.finish(Outcome::Failure);
+
+        let buf = render(throbber.clone(), &mut throbber_state, 6, 1);
+        assert_eq!(buf[(0, 0)].symbol(), "✖");
+        assert_eq!(buf[(0, 0)].fg, ratatui::style::Color::Red);
+        assert_eq!(buf[(0, 0)].bg, ratatui::style::Color::Black);
+        assert_eq!(buf[(2, 0)].symbol(), "d");
+
+        // Back to spinning.
+        throbber_state.set_outcome(None);
+        assert_eq!(throbber.to_symbol_span(&throbber_state).content, "⠷ ");
+
+        // Stateless outcome by use_type.
+        let span: ratatui::text::Span = throbber
+            .use_type(WhichUse::Outcome(Outcome::Warning))
+            .into();
+        assert_eq!(span.content, "⚠ ");
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn throbber_state_freezes_elapsed_at_finish() {
+        let mut throbber_state = ThrobberState::default();
+        throbber_state.start();
+        throbber_state.finish(crate::symbols::throbber::Outcome::Success);
+        assert!(!throbber_state.is_time_driven());
+        let elapsed = throbber_state.elapsed();
+        assert!(elapsed.is_some());
+        std::thread::sleep(core::time::Duration::from_millis(2));
+        assert_eq!(throbber_state.elapsed(), elapsed);
+    }
+
+    #[test]
+    fn throbber_renders_elapsed() {
+        let format = super::super::ElapsedFormat::default()
+            .position(super::super::ElapsedPosition::BeforeLabel)
+            .style(ratatui::style::Style::default().fg(ratatui::style::Color::DarkGray));
+        let throbber = Throbber::default().label("a").elapsed_format(format);
+        let mut throbber_state = ThrobberState::default();
+
+        // Nothing is rendered without elapsed time.
+        assert_eq!(throbber.to_line(&throbber_state).to_string(), "⠷ a");
+
+        throbber_state.calc_elapsed(core::time::Duration::from_secs(3), &throbber);
+        let buf = render(throbber, &mut throbber_state, 8, 1);
+        let mut expected = ratatui::buffer::Buffer::with_lines(["⠯ 3s a  "]);
+        expected.set_style(
+            ratatui::layout::Rect::new(2, 0, 3, 1),
+            ratatui::style::Style::default().fg(ratatui::style::Color::DarkGray),
+        );
+        assert_eq!(buf, expected);
+    }
+
+    /// Render the throbber in an area of the size.
+    fn render(
+        throbber: Throbber,
+        throbber_state: &mut ThrobberState,
+        width: u16,
+        height: u16,
+    ) -> ratatui::buffer::Buffer {
+        let area = ratatui::layout::Rect::new(0, 0, width, height);
+        let mut buf = ratatui::buffer::Buffer::empty(area);
+        ratatui::widgets::StatefulWidget::render(throbber, area, &mut buf, throbber_state);
+        buf
+    }
+
+    fn render_bar(throbber: Throbber, index: i64, width: u16) -> ratatui::buffer::Buffer {
+        let mut throbber_state = ThrobberState {
+            index,
+            ..Default::default()
+        };
+        render(throbber, &mut throbber_state, width, 1)
+    }
+
+    #[test]
+    fn throbber_renders_bounce() {
+        use crate::symbols::throbber::{WhichUse, HORIZONTAL_BLOCK};
+        let throbber = Throbber::default()
+            .throbber_set(HORIZONTAL_BLOCK)
+            .use_type(WhichUse::Bounce)
+            .bar_width(2)
+            .label("ab");
+
+        // 4 cells for the bar, 1 for the space, 2 for the label.
+        let buf = render_bar(throbber.clone(), 0, 7);
+        assert_eq!(buf, ratatui::buffer::Buffer::with_lines(["██   ab"]));
+
+        // Sub-cell position: the left edge is drawn reversed.
+        let buf = render_bar(throbber.clone(), 3, 7);
+        assert_eq!(buf[(0, 0)].symbol(), "▍");
+        assert!(buf[(0, 0)]
+            .modifier
+            .contains(ratatui::style::Modifier::REVERSED));
+        assert_eq!(buf[(1, 0)].symbol(), "█");
+        assert_eq!(buf[(2, 0)].symbol(), "▍");
+        assert!(!buf[(2, 0)]
+            .modifier
+            .contains(ratatui::style::Modifier::REVERSED));
+
+        // The right end, and back.
+        let buf = render_bar(throbber.clone(), 16, 7);
+        assert_eq!(buf, ratatui::buffer::Buffer::with_lines(["  ██ ab"]));
+        let buf = render_bar(throbber.clone(), 24, 7);
+        assert_eq!(buf, ratatui::buffer::Buffer::with_lines([" ██  ab"]));
+
+        // Too narrow, so a symbol is rendered.
+        let buf = render_bar(throbber, 0, 5);
+        assert_eq!(buf, ratatui::buffer::Buffer::with_lines(["▏ ab "]));
+    }
+
+    #[test]
+    fn throbber_renders_separator_and_padding() {
+        use crate::symbols::throbber::{WhichUse, HORIZONTAL_BLOCK};
+        let throbber = Throbber::default()
+            .use_type(WhichUse::Full)
+            .label("ab")
+            .separator(" | ")
+            .padding(1, 2);
+        let buf = render_bar(throbber.clone(), 0, 10);
+        assert_eq!(buf, ratatui::buffer::Buffer::with_lines([" ⠿ | ab   "]));
+
+        // The separator takes the place of the space between the bar and the label.
+        let throbber = throbber
+            .throbber_set(HORIZONTAL_BLOCK)
+            .use_type(WhichUse::Bounce)
+            .bar_width(2);
+        let buf = render_bar(throbber, 0, 12);
+        assert_eq!(buf, ratatui::buffer::Buffer::with_lines([" ██   | ab  "]));
+    }
+
+    #[test]
+    fn throbber_truncates_stacked_bar_label() {
+        let throbber = Throbber::default()
+            .throbber_set(crate::symbols::throbber::HORIZONTAL_BLOCK)
+            .use_type(crate::symbols::throbber::WhichUse::Bounce)
+            .label("Loading")
+            .label_position(LabelPosition::Below)
+            .label_truncation(crate::widgets::LabelTruncation::End)
+            .bar_width(1);
+        let buf = render(throbber.clone(), &mut ThrobberState::default(), 5, 2);
+        assert_eq!(buf, ratatui::buffer::Buffer::with_lines(["█    ", "Load…"]));
+
+        let buf = render(
+            throbber.label_min_width(6),
+            &mut ThrobberState::default(),
+            5,
+            2,
+        );
+        assert_eq!(buf, ratatui::buffer::Buffer::with_lines(["█    ", "     "]));
+    }
+
+    #[test]
+    fn throbber_includes_styled_separator_in_span_and_line() {
+        use ratatui::style::{Color, Stylize};
+        let throbber = Throbber::default()
+            .throbber_style(ratatui::style::Style::default().fg(Color::Cyan))
+            .separator(" | ".dark_gray())
+            .padding(1, 1);
+        let throbber_state = ThrobberState::default();
+
+        let line = throbber.clone().label("ab").to_line(&throbber_state);
+        assert_eq!(line.to_string(), " ⠷ | ab ");
+        assert_eq!(line.spans[2].content, " | ");
+        assert_eq!(line.spans[2].style.fg, Some(Color::DarkGray));
+
+        let line = throbber.to_line(&throbber_state);
+        let span = throbber.to_symbol_span(&throbber_state);
+        assert_eq!(span.content, line.to_string());
+        assert_eq!(span.content, " ⠷ |  ");
+        assert_eq!(span.style.fg, Some(Color::Cyan));
+    }
+
+    #[test]
+    fn throbber_hides_label_below_min_width() {
+        let throbber = Throbber::default()
+            .use_type(crate::symbols::throbber::WhichUse::Full)
+            .label("Loading")
+            .label_truncation(crate::widgets::LabelTruncation::End)
+            .label_min_width(4);
+        let buf = render_bar(throbber.clone(), 0, 7);
+        assert_eq!(buf, ratatui::buffer::Buffer::with_lines(["⠿ Load…"]));
+        let buf = render_bar(throbber, 0, 5);
+        assert_eq!(buf, ratatui::buffer::Buffer::with_lines(["⠿    "]));
+    }
+
+    #[test]
+    fn throbber_renders_wrapped_text_label() {
+        use ratatui::style::{Color, Style, Stylize};
+        let label = ratatui::text::Text::from(alloc::vec![
+            ratatui::text::Line::from(alloc::vec!["fetch".bold(), " the crates".into()]),
+            ratatui::text::Line::from("ok"),
+        ])
+        .fg(Color::Red);
+        let throbber = Throbber::default()
+            .style(Style::default().bg(Color::Blue))
+            .use_type(crate::symbols::throbber::WhichUse::Full)
+            .label(label)
+            .label_wrap(true);
+        let buf = render(throbber, &mut ThrobberState::default(), 11, 4);
+        let mut expected = ratatui::buffer::Buffer::with_lines([
+            "⠿ fetch the",
+            "  crates   ",
+            "  ok       ",
+            "           ",
+        ]);
+        expected.set_style(buf.area, Style::default().bg(Color::Blue));
+        expected.set_style(
+            ratatui::layout::Rect::new(2, 0, 9, 1),
+            Style::default().fg(Color::Red),
+        );
+        expected.set_style(
+            ratatui::layout::Rect::new(2, 0, 5, 1),
+            Style::default().bold(),
+        );
+        expected.set_style(
+            ratatui::layout::Rect::new(2, 1, 6, 1),
+            Style::default().fg(Color::Red),
+        );
+        expected.set_style(
+            ratatui::layout::Rect::new(2, 2, 2, 1),
+            Style::default().fg(Color::Red),
+        );
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn throbber_aligns_label_rows() {
+        let label = ratatui::text::Text::from("ab\ncdef");
+        let throbber = Throbber::default()
+            .use_type(crate::symbols::throbber::WhichUse::Full)
+            .label(label)
+            .alignment(ratatui::layout::HorizontalAlignment::Right);
+        assert_eq!(
+            crate::widgets::render_rows(throbber.clone(), &mut ThrobberState::default(), 2),
+            ["              ⠿ ab", "                cdef"]
+        );
+        assert_eq!(
+            crate::widgets::render_rows(
+                throbber.label_position(LabelPosition::Left),
+                &mut ThrobberState::default(),
+                2
+            ),
+            ["                ab ⠿", "                cdef"]
+        );
+    }
+
+    #[test]
+    fn throbber_renders_bar_with_label_rows() {
+        use crate::symbols::throbber::{WhichUse, HORIZONTAL_BLOCK};
+        let throbber = Throbber::default()
+            .throbber_set(HORIZONTAL_BLOCK)
+            .use_type(WhichUse::Bounce)
+            .label(ratatui::text::Text::from("ab\ncd"))
+            .bar_width(1);
+        assert_eq!(
+            render(throbber.clone(), &mut ThrobberState::default(), 8, 2),
+            ratatui::buffer::Buffer::with_lines(["█     ab", "      cd"])
+        );
+        assert_eq!(
+            render(
+                throbber.clone().label_position(LabelPosition::Left),
+                &mut ThrobberState::default(),
+                8,
+                2
+            ),
+            ratatui::buffer::Buffer::with_lines(["ab █    ", "cd      "])
+        );
+
+        // Wrapped rows are stacked below the bar.
+        let throbber = throbber
+            .label("ab cd")
+            .label_wrap(true)
+            .label_position(LabelPosition::Below);
+        assert_eq!(
+            render(throbber, &mut ThrobberState::default(), 3, 3),
+            ratatui::buffer::Buffer::with_lines(["█  ", "ab ", "cd "])
+        );
+    }
+
+    #[test]
+    fn throbber_renders_in_block() {
+        let throbber = Throbber::default()
+            .use_type(crate::symbols::throbber::WhichUse::Full)
+            .label("ab")
+            .block(ratatui::widgets::Block::bordered().title("t"));
+        assert_eq!(
+            render(throbber, &mut ThrobberState::default(), 7, 3),
+            ratatui::buffer::Buffer::with_lines(["┌t────┐", "│⠿ ab │", "└─────┘"])
+        );
+    }
+
+    #[test]
+    fn throbber_renders_gauge_with_total() {
+        use crate::symbols::throbber::{WhichUse, HORIZONTAL_BLOCK};
+        let throbber = Throbber::default()
+            .throbber_set(HORIZONTAL_BLOCK)
+            .use_type(WhichUse::Bounce);
+        let mut throbber_state = ThrobberState::default();
+        throbber_state.set_total(Some(8));
+        throbber_state.set_position(3);
+
+        // 4 cells for the bar, 1 for the space, 3 for the percent.
+        let buf = render(throbber, &mut throbber_state, 8, 1);
+        assert_eq!(buf, ratatui::buffer::Buffer::with_lines(["█▌   37%"]));
+    }
+
+    #[test]
+    fn throbber_renders_sweep() {
+        use crate::symbols::throbber::{WhichUse, ASCII};
+        let throbber = Throbber::default()
+            .throbber_set(ASCII)
+            .use_type(WhichUse::Sweep)
+            .bar_width(1);
+
+        // The block enters from the left.
+        let buf = render_bar(throbber.clone(), 0, 4);
+        assert_eq!(buf, ratatui::buffer::Buffer::with_lines(["    "]));
+        let buf = render_bar(throbber.clone(), 6, 4);
+        assert_eq!(buf[(0, 0)].symbol(), "/");
+        assert!(buf[(0, 0)]
+            .modifier
+            .contains(ratatui::style::Modifier::REVERSED));
+        assert_eq!(buf[(1, 0)].symbol(), "/");
+        let buf = render_bar(throbber.clone(), 8, 4);
+        assert_eq!(buf, ratatui::buffer::Buffer::with_lines([" *  "]));
+
+        // The block exits to the right, and enters again.
+        let buf = render_bar(throbber.clone(), 19, 4);
+        assert_eq!(buf[(3, 0)].symbol(), "-");
+        let buf = render_bar(throbber, 20, 4);
+        assert_eq!(buf, ratatui::buffer::Buffer::with_lines(["    "]));
+    }
+
     #[test]
     fn throbber_converts_to_span() {
         let throbber = Throbber::default().use_type(crate::symbols::throbber::WhichUse::Full);
@@ -366,14 +2169,40 @@ mod tests {
         assert_eq!(line.spans[0].content, "⠿ ");
     }
 
+    /// Step across the wrap boundary and check that the normalized index moves by one each time.
+    fn assert_seamless(set: crate::symbols::throbber::Set, start: i64, step: i64) {
+        let throbber = Throbber::default().throbber_set(set.clone());
+        let len = set.symbols.len() as i64;
+        let mut throbber_state = ThrobberState {
+            index: start,
+            ..Default::default()
+        };
+        let mut normalized = throbber_state.clone();
+        normalized.normalize(&throbber);
+        let mut prev = normalized.index();
+        for _ in 0..len * 2 {
+            throbber_state.calc_step(step);
+            let mut normalized = throbber_state.clone();
+            normalized.normalize(&throbber);
+            assert_eq!(normalized.index(), (prev + step).rem_euclid(len), "{set:?}");
+            prev = normalized.index();
+        }
+    }
+
     #[test]
-    fn throbber_reaches_upper_limit_step_resets_to_zero() {
-        let mut throbber_state = ThrobberState::default();
+    fn throbber_state_cycles_seamlessly_across_wrap() {
+        for (_, set) in crate::symbols::throbber::BUILTIN_SETS {
+            assert_seamless(set.clone(), ThrobberState::INDEX_PERIOD - 5, 1);
+            assert_seamless(set.clone(), -ThrobberState::INDEX_PERIOD + 5, -1);
+        }
+    }
 
-        for _ in 0..i8::MAX {
+    #[test]
+    fn throbber_state_does_not_reset_at_former_i8_limit() {
+        let mut throbber_state = ThrobberState::default();
+        for _ in 0..1000 {
             throbber_state.calc_next();
         }
-        throbber_state.calc_next();
-        assert!(throbber_state.index() != i8::MAX);
+        assert_eq!(throbber_state.index(), 1000);
     }
 }
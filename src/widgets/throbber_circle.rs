@@ -0,0 +1,306 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+use super::throbber::ThrobberState;
+
+/// Bits of a braille pattern by the dot position `[y][x]` in a cell.
+const BRAILLE_BITS: [[u8; 2]; 4] = [[0x01, 0x08], [0x02, 0x10], [0x04, 0x20], [0x40, 0x80]];
+
+/// A widget to display an arc rotating around a circle drawn with braille dots.
+///
+/// The circle is scaled to fit in the area. A cell has 2x4 dots, so it looks round in most terminals.
+/// The arc moves by one step every time the index of ThrobberState increases.
+/// When ThrobberState has an outcome, the whole circle is drawn in the style of the outcome,
+/// with its symbol at the center if there is room.
+///
+/// # Examples:
+/// ```
+/// let circle = throbber_widgets_tui::ThrobberCircle::default()
+///     .throbber_style(ratatui::style::Style::default().fg(ratatui::style::Color::Cyan))
+///     .track_style(ratatui::style::Style::default().fg(ratatui::style::Color::DarkGray))
+///     .steps(32)
+///     .arc_steps(8);
+/// let mut throbber_state = throbber_widgets_tui::ThrobberState::default();
+/// throbber_state.calc_next();
+/// // frame.render_stateful_widget(circle, chunks[0], &mut throbber_state);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ThrobberCircle {
+    style: ratatui::style::Style,
+    throbber_style: ratatui::style::Style,
+    track_style: Option<ratatui::style::Style>,
+    steps: u16,
+    arc_steps: u16,
+    interval: core::time::Duration,
+    outcomes: crate::symbols::throbber::OutcomeSet,
+}
+
+impl Default for ThrobberCircle {
+    fn default() -> Self {
+        Self {
+            style: ratatui::style::Style::default(),
+            throbber_style: ratatui::style::Style::default(),
+            track_style: None,
+            steps: 24,
+            arc_steps: 6,
+            interval: core::time::Duration::from_millis(40),
+            outcomes: crate::symbols::throbber::OUTCOME_UNICODE,
+        }
+    }
+}
+
+impl ThrobberCircle {
+    /// Style of the whole area.
+    pub fn style(mut self, style: ratatui::style::Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Style of the arc.
+    pub fn throbber_style(mut self, style: ratatui::style::Style) -> Self {
+        self.throbber_style = style;
+        self
+    }
+
+    /// If specified, the rest of the circle is also drawn with this style.
+    ///
+    /// A cell containing the arc is drawn with throbber_style.
+    pub fn track_style(mut self, style: ratatui::style::Style) -> Self {
+        self.track_style = Some(style);
+        self
+    }
+
+    /// Number of steps for one revolution. Default is 24.
+    pub fn steps(mut self, steps: u16) -> Self {
+        self.steps = steps.max(1);
+        self
+    }
+
+    /// Length of the arc in steps. Default is 6, that is, a quarter of the circle.
+    pub fn arc_steps(mut self, arc_steps: u16) -> Self {
+        self.arc_steps = arc_steps;
+        self
+    }
+
+    /// Duration of one step for a time-driven ThrobberState. Default is 40ms.
+    ///
+    /// The interval of ThrobberState takes precedence.
+    pub fn interval(mut self, interval: core::time::Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    /// Symbols and styles for the outcome of ThrobberState. Default is OUTCOME_UNICODE.
+    pub fn outcomes(mut self, outcomes: crate::symbols::throbber::OutcomeSet) -> Self {
+        self.outcomes = outcomes;
+        self
+    }
+}
+
+/// Monotonic substitute for the angle of (x, y) in `[0, 4)`, without trigonometry.
+fn pseudo_angle(x: i32, y: i32) -> f32 {
+    let (x, y) = (x as f32, y as f32);
+    if 0.0 <= y {
+        if 0.0 <= x {
+            y / (x + y)
+        } else {
+            1.0 - x / (-x + y)
+        }
+    } else if x < 0.0 {
+        2.0 - y / (-x - y)
+    } else {
+        3.0 + x / (x - y)
+    }
+}
+
+/// Get the dots of the circle by the midpoint circle algorithm,
+/// in clockwise order from the top.
+fn circle_points(radius: i32) -> Vec<(i32, i32)> {
+    let mut points = Vec::new();
+    let (mut x, mut y, mut d) = (radius, 0, 1 - radius);
+    while y <= x {
+        points.extend([
+            (x, y),
+            (y, x),
+            (-y, x),
+            (-x, y),
+            (-x, -y),
+            (-y, -x),
+            (y, -x),
+            (x, -y),
+        ]);
+        y += 1;
+        if d < 0 {
+            d += 2 * y + 1;
+        } else {
+            x -= 1;
+            d += 2 * (y - x) + 1;
+        }
+    }
+    // Rotate by a quarter so that the order starts from the top (y is downward).
+    points.sort_by(|(ax, ay), (bx, by)| {
+        pseudo_angle(-ay, *ax)
+            .partial_cmp(&pseudo_angle(-by, *bx))
+            .unwrap_or(core::cmp::Ordering::Equal)
+    });
+    points.dedup();
+    points
+}
+
+impl ratatui::widgets::Widget for ThrobberCircle {
+    /// Render random step arc.
+    fn render(self, area: ratatui::layout::Rect, buf: &mut ratatui::buffer::Buffer) {
+        let mut state = ThrobberState::default();
+        state.calc_step(0);
+        ratatui::widgets::StatefulWidget::render(self, area, buf, &mut state);
+    }
+}
+
+impl ratatui::widgets::StatefulWidget for ThrobberCircle {
+    type State = ThrobberState;
+
+    /// Render the circle at the center of area.
+    fn render(
+        self,
+        area: ratatui::layout::Rect,
+        buf: &mut ratatui::buffer::Buffer,
+        state: &mut Self::State,
+    ) {
+        buf.set_style(area, self.style);
+
+        let (width, height) = (area.width as i32 * 2, area.height as i32 * 4);
+        let radius = (width.min(height) - 1) / 2;
+        if radius < 1 {
+            return;
+        }
+        let outcome = state.outcome().map(|outcome| self.outcomes.get(outcome));
+        if outcome.is_none() {
+            state.calc_time_driven_by(self.interval);
+        }
+        let throbber_style = outcome.map_or(self.throbber_style, |outcome| {
+            self.throbber_style.patch(outcome.style)
+        });
+
+        let points = circle_points(radius);
+        let n = points.len() as i64;
+        let steps = self.steps as i64;
+        let (start, len) = match outcome {
+            // A finished circle is drawn as a whole.
+            Some(_) => (0, n),
+            None => (
+                state.index().rem_euclid(steps) * n / steps,
+                (self.arc_steps.min(self.steps) as i64 * n / steps).max(1),
+            ),
+        };
+        let (cx, cy) = ((width - 1) / 2, (height - 1) / 2);
+
+        let cells = area.width as usize * area.height as usize;
+        let (mut arc, mut track) = (vec![0u8; cells], vec![0u8; cells]);
+        for (rank, (dx, dy)) in points.into_iter().enumerate() {
+            let (x, y) = (cx + dx, cy + dy);
+            let cell = (y / 4 * area.width as i32 + x / 2) as usize;
+            let bit = BRAILLE_BITS[(y % 4) as usize][(x % 2) as usize];
+            if (rank as i64 - start).rem_euclid(n) < len {
+                arc[cell] |= bit;
+            } else {
+                track[cell] |= bit;
+            }
+        }
+
+        let center = (cy / 4 * area.width as i32 + cx / 2) as usize;
+        let is_center_empty = arc[center] == 0;
+        for (i, (arc, track)) in arc.into_iter().zip(track).enumerate() {
+            let (dots, style) = match (arc, self.track_style) {
+                (0, Some(track_style)) if track != 0 => (track, track_style),
+                (0, _) => continue,
+                (_, Some(_)) => (arc | track, throbber_style),
+                (_, None) => (arc, throbber_style),
+            };
+            let x = area.left() + (i % area.width as usize) as u16;
+            let y = area.top() + (i / area.width as usize) as u16;
+            if let Some(symbol) = char::from_u32(0x2800 + dots as u32) {
+                buf[(x, y)].set_char(symbol).set_style(style);
+            }
+        }
+
+        if let Some(outcome) = outcome.filter(|_| is_center_empty) {
+            let x = area.left() + (cx / 2) as u16;
+            let y = area.top() + (cy / 4) as u16;
+            let width = (area.right() - x) as usize;
+            buf.set_stringn(x, y, &outcome.symbol, width, throbber_style);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn circle_points_are_clockwise_from_top() {
+        let points = circle_points(3);
+        assert_eq!(points[0], (0, -3));
+        assert_eq!(points[points.len() / 4], (3, 0));
+        assert_eq!(points[points.len() / 2], (0, 3));
+        assert_eq!(points[points.len() * 3 / 4], (-3, 0));
+    }
+
+    #[test]
+    fn throbber_circle_renders_arc() {
+        let area = ratatui::layout::Rect::new(0, 0, 4, 2);
+        let mut throbber_state = ThrobberState::default();
+
+        let mut buf = ratatui::buffer::Buffer::empty(area);
+        let circle = ThrobberCircle::default().steps(4).arc_steps(1);
+        ratatui::widgets::StatefulWidget::render(
+            circle.clone(),
+            area,
+            &mut buf,
+            &mut throbber_state,
+        );
+        assert_eq!(buf, ratatui::buffer::Buffer::with_lines([" ⠈⠑⠄", "    "]));
+
+        throbber_state.calc_next();
+        let mut buf = ratatui::buffer::Buffer::empty(area);
+        ratatui::widgets::StatefulWidget::render(
+            circle.clone(),
+            area,
+            &mut buf,
+            &mut throbber_state,
+        );
+        assert_eq!(buf, ratatui::buffer::Buffer::with_lines(["   ⡀", "  ⠔⠁"]));
+
+        let mut buf = ratatui::buffer::Buffer::empty(area);
+        let circle = circle.track_style(ratatui::style::Style::default());
+        ratatui::widgets::StatefulWidget::render(circle, area, &mut buf, &mut throbber_state);
+        assert_eq!(buf, ratatui::buffer::Buffer::with_lines(["⡔⠉⠑⡄", "⠑⠤⠔⠁"]));
+    }
+
+    #[test]
+    fn throbber_circle_renders_outcome() {
+        let mut throbber_state = ThrobberState::default();
+        throbber_state.calc_next();
+        throbber_state.finish(crate::symbols::throbber::Outcome::Success);
+        let green = ratatui::style::Style::default().fg(ratatui::style::Color::Green);
+
+        // Too small for the symbol, so only the whole circle is drawn.
+        let area = ratatui::layout::Rect::new(0, 0, 4, 2);
+        let mut buf = ratatui::buffer::Buffer::empty(area);
+        let circle = ThrobberCircle::default().steps(4).arc_steps(1);
+        ratatui::widgets::StatefulWidget::render(circle, area, &mut buf, &mut throbber_state);
+        let mut expected = ratatui::buffer::Buffer::with_lines(["⡔⠉⠑⡄", "⠑⠤⠔⠁"]);
+        expected.set_style(area, green);
+        assert_eq!(buf, expected);
+
+        let area = ratatui::layout::Rect::new(0, 0, 7, 4);
+        let mut buf = ratatui::buffer::Buffer::empty(area);
+        ratatui::widgets::StatefulWidget::render(
+            ThrobberCircle::default(),
+            area,
+            &mut buf,
+            &mut throbber_state,
+        );
+        assert_eq!(buf[(3, 1)].symbol(), "✔");
+        assert_eq!(buf[(3, 1)].fg, ratatui::style::Color::Green);
+    }
+}
@@ -0,0 +1,347 @@
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use super::throbber::{Throbber, ThrobberState};
+
+/// A task to be displayed as a row of ThrobberList.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ThrobberTask {
+    label: String,
+    state: ThrobberState,
+}
+
+impl ThrobberTask {
+    pub fn new<T>(label: T) -> Self
+    where
+        T: Into<String>,
+    {
+        Self {
+            label: label.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Get the label.
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    /// Set the label.
+    pub fn set_label<T>(&mut self, label: T)
+    where
+        T: Into<String>,
+    {
+        self.label = label.into();
+    }
+
+    /// Get the throbber state.
+    pub fn state(&self) -> &ThrobberState {
+        &self.state
+    }
+
+    /// Get the mutable throbber state.
+    pub fn state_mut(&mut self) -> &mut ThrobberState {
+        &mut self.state
+    }
+
+    /// Get the elapsed time to be displayed.
+    ///
+    /// See `ThrobberState::elapsed()`.
+    pub fn elapsed(&self) -> Option<core::time::Duration> {
+        self.state.elapsed()
+    }
+
+    /// Set the elapsed time to be displayed.
+    ///
+    /// See `ThrobberState::set_elapsed()`.
+    pub fn set_elapsed(&mut self, elapsed: Option<core::time::Duration>) {
+        self.state.set_elapsed(elapsed);
+    }
+
+    /// Get the outcome of the task.
+    pub fn outcome(&self) -> Option<crate::symbols::throbber::Outcome> {
+        self.state.outcome()
+    }
+
+    /// Finish the task with the outcome.
+    pub fn finish(&mut self, outcome: crate::symbols::throbber::Outcome) {
+        self.state.finish(outcome);
+    }
+
+    /// Whether the task has an outcome.
+    pub fn is_finished(&self) -> bool {
+        self.state.is_finished()
+    }
+
+    /// Convert the task to Line with the throbber and the label.
+    pub(crate) fn to_line<'b>(
+        &'b self,
+        throbber: &Throbber<'b>,
+        state: &ThrobberState,
+    ) -> ratatui::text::Line<'b> {
+        throbber.clone().label(self.label()).to_line(state)
+    }
+}
+
+/// State to be used for ThrobberList render.
+///
+/// It owns the tasks and the scroll offset.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ThrobberListState {
+    tasks: Vec<ThrobberTask>,
+    /// Index of the first visible row.
+    offset: usize,
+}
+
+impl ThrobberListState {
+    /// Add a task and return its index.
+    ///
+    /// # Examples:
+    /// ```
+    /// let mut list_state = throbber_widgets_tui::ThrobberListState::default();
+    /// let build = list_state.push(throbber_widgets_tui::ThrobberTask::new("build"));
+    /// let test = list_state.push(throbber_widgets_tui::ThrobberTask::new("test"));
+    /// assert_eq!((build, test), (0, 1));
+    /// assert_eq!(list_state.len(), 2);
+    /// ```
+    pub fn push(&mut self, task: ThrobberTask) -> usize {
+        self.tasks.push(task);
+        self.tasks.len() - 1
+    }
+
+    /// Remove the task at index.
+    pub fn remove(&mut self, index: usize) -> Option<ThrobberTask> {
+        (index < self.tasks.len()).then(|| self.tasks.remove(index))
+    }
+
+    /// Remove all tasks.
+    pub fn clear(&mut self) {
+        self.tasks.clear();
+        self.offset = 0;
+    }
+
+    /// Get all tasks.
+    pub fn tasks(&self) -> &[ThrobberTask] {
+        &self.tasks
+    }
+
+    /// Get the mutable task at index.
+    pub fn task_mut(&mut self, index: usize) -> Option<&mut ThrobberTask> {
+        self.tasks.get_mut(index)
+    }
+
+    pub fn len(&self) -> usize {
+        self.tasks.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.tasks.is_empty()
+    }
+
+    /// Number of finished tasks.
+    pub fn finished_count(&self) -> usize {
+        self.tasks.iter().filter(|task| task.is_finished()).count()
+    }
+
+    /// Increase the index of all unfinished tasks.
+    ///
+    /// # Examples:
+    /// ```
+    /// let mut list_state = throbber_widgets_tui::ThrobberListState::default();
+    /// list_state.push(throbber_widgets_tui::ThrobberTask::new("build"));
+    /// list_state.push(throbber_widgets_tui::ThrobberTask::new("test"));
+    /// list_state.calc_next();
+    /// assert!(list_state.tasks().iter().all(|task| task.state().index() == 1));
+    /// ```
+    pub fn calc_next(&mut self) {
+        self.calc_step(1);
+    }
+
+    /// Calculate the index of all unfinished tasks by specifying step.
+    ///
+    /// See `ThrobberState::calc_step()`.
+    pub fn calc_step(&mut self, step: impl Into<i64>) {
+        let step: i64 = step.into();
+        self.tasks
+            .iter_mut()
+            .filter(|task| !task.is_finished())
+            .for_each(|task| task.state.calc_step(step));
+    }
+
+    /// Get the index of the first visible row.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Set the index of the first visible row.
+    ///
+    /// If too large, it is clamped at render time.
+    pub fn set_offset(&mut self, offset: usize) {
+        self.offset = offset;
+    }
+
+    pub fn scroll_up(&mut self, amount: usize) {
+        self.offset = self.offset.saturating_sub(amount);
+    }
+
+    pub fn scroll_down(&mut self, amount: usize) {
+        self.offset = self.offset.saturating_add(amount);
+    }
+}
+
+/// A widget to display multiple tasks with throbbers vertically.
+///
+/// Each task is rendered as a row of `throbber` with its label.
+/// By default, the elapsed time of the task is also displayed after the label.
+///
+/// # Examples:
+///
+/// ```
+/// let list = throbber_widgets_tui::ThrobberList::default()
+///     .throbber(throbber_widgets_tui::Throbber::default().throbber_set(throbber_widgets_tui::BRAILLE_EIGHT))
+///     .collapse_finished(true);
+/// let mut list_state = throbber_widgets_tui::ThrobberListState::default();
+/// list_state.push(throbber_widgets_tui::ThrobberTask::new("build"));
+/// // frame.render_stateful_widget(list, chunks[0], &mut list_state);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ThrobberList<'a> {
+    throbber: Throbber<'a>,
+    collapse_finished: bool,
+}
+
+impl Default for ThrobberList<'_> {
+    fn default() -> Self {
+        Self {
+            throbber: Throbber::default().elapsed_format(super::ElapsedFormat::default()),
+            collapse_finished: false,
+        }
+    }
+}
+
+impl<'a> ThrobberList<'a> {
+    /// Throbber used to render each task. Its label is replaced by the task label.
+    pub fn throbber(mut self, throbber: Throbber<'a>) -> Self {
+        self.throbber = throbber;
+        self
+    }
+
+    /// If true, finished tasks are collapsed into one summary row at the bottom.
+    pub fn collapse_finished(mut self, collapse_finished: bool) -> Self {
+        self.collapse_finished = collapse_finished;
+        self
+    }
+
+    /// Convert the counts of finished tasks to Line.
+    fn summary_line(&self, tasks: &[ThrobberTask]) -> ratatui::text::Line<'static> {
+        use crate::symbols::throbber::Outcome;
+        let mut line = ratatui::text::Line::default();
+        for outcome in [
+            Outcome::Success,
+            Outcome::Failure,
+            Outcome::Warning,
+            Outcome::Skipped,
+            Outcome::Cancelled,
+        ] {
+            let count = tasks
+                .iter()
+                .filter(|task| task.outcome() == Some(outcome))
+                .count();
+            if count == 0 {
+                continue;
+            }
+            let symbol = self.throbber.throbber_set.outcomes.get(outcome);
+            let text = format!("{} {} ", symbol.symbol, count);
+            line.spans.push(ratatui::text::Span::styled(
+                text,
+                self.throbber.throbber_style.patch(symbol.style),
+            ));
+        }
+        line.spans.push(ratatui::text::Span::raw("finished"));
+        line
+    }
+}
+
+impl ratatui::widgets::StatefulWidget for ThrobberList<'_> {
+    type State = ThrobberListState;
+
+    /// Render visible tasks from the offset.
+    fn render(
+        self,
+        area: ratatui::layout::Rect,
+        buf: &mut ratatui::buffer::Buffer,
+        state: &mut Self::State,
+    ) {
+        buf.set_style(area, self.throbber.style);
+
+        let mut lines: Vec<ratatui::text::Line> = state
+            .tasks
+            .iter()
+            .filter(|task| !(self.collapse_finished && task.is_finished()))
+            .map(|task| task.to_line(&self.throbber, &task.state))
+            .collect();
+        if self.collapse_finished && state.tasks.iter().any(|task| task.is_finished()) {
+            lines.push(self.summary_line(&state.tasks));
+        }
+
+        let height = area.height as usize;
+        let offset = state.offset.min(lines.len().saturating_sub(height));
+        for (i, line) in lines.iter().skip(offset).take(height).enumerate() {
+            buf.set_line(area.left(), area.top() + i as u16, line, area.width);
+        }
+        state.offset = offset;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::symbols::throbber::Outcome;
+    use crate::widgets::render_rows as render;
+
+    #[test]
+    fn throbber_list_scrolls() {
+        let mut list_state = ThrobberListState::default();
+        for label in ["a", "b", "c", "d"] {
+            list_state.push(ThrobberTask::new(label));
+        }
+        list_state.calc_next();
+
+        let rows = render(ThrobberList::default(), &mut list_state, 2);
+        assert_eq!(rows, ["⠯ a", "⠯ b"]);
+
+        // The offset is clamped to show the last rows.
+        list_state.scroll_down(10);
+        let rows = render(ThrobberList::default(), &mut list_state, 2);
+        assert_eq!(rows, ["⠯ c", "⠯ d"]);
+        assert_eq!(list_state.offset(), 2);
+    }
+
+    #[test]
+    fn throbber_list_collapses_finished() {
+        let mut list_state = ThrobberListState::default();
+        for label in ["a", "b", "c", "d"] {
+            list_state.push(ThrobberTask::new(label));
+        }
+        list_state.task_mut(0).unwrap().finish(Outcome::Success);
+        list_state.task_mut(2).unwrap().finish(Outcome::Failure);
+        list_state.task_mut(3).unwrap().finish(Outcome::Success);
+        list_state
+            .task_mut(1)
+            .unwrap()
+            .set_elapsed(Some(core::time::Duration::from_secs(65)));
+
+        // Finished tasks are not ticked.
+        list_state.calc_next();
+        assert_eq!(list_state.tasks()[0].state().index(), 0);
+        assert_eq!(list_state.finished_count(), 3);
+
+        let rows = render(ThrobberList::default(), &mut list_state, 5);
+        assert_eq!(rows, ["✔ a", "⠯ b 1m 05s", "✖ c", "✔ d", ""]);
+
+        let list = ThrobberList::default().collapse_finished(true);
+        let rows = render(list, &mut list_state, 3);
+        assert_eq!(rows, ["⠯ b 1m 05s", "✔ 2 ✖ 1 finished", ""]);
+    }
+}
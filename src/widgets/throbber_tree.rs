@@ -0,0 +1,468 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use super::throbber::Throbber;
+use super::throbber_list::ThrobberTask;
+use crate::symbols::throbber::{display_width, Outcome};
+
+/// A node of ThrobberTree, which is a task with subtasks.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ThrobberTreeNode {
+    task: ThrobberTask,
+    children: Vec<ThrobberTreeNode>,
+    /// If true, children are hidden.
+    collapsed: bool,
+}
+
+impl ThrobberTreeNode {
+    pub fn new<T>(label: T) -> Self
+    where
+        T: Into<String>,
+    {
+        Self {
+            task: ThrobberTask::new(label),
+            ..Default::default()
+        }
+    }
+
+    /// Add a child and return self.
+    ///
+    /// # Examples:
+    /// ```
+    /// use throbber_widgets_tui::ThrobberTreeNode;
+    /// let deploy = ThrobberTreeNode::new("deploy")
+    ///     .child(ThrobberTreeNode::new("build"))
+    ///     .child(ThrobberTreeNode::new("upload"));
+    /// assert_eq!(deploy.children().len(), 2);
+    /// ```
+    pub fn child(mut self, child: ThrobberTreeNode) -> Self {
+        self.children.push(child);
+        self
+    }
+
+    /// Add a child and return its index.
+    pub fn push(&mut self, child: ThrobberTreeNode) -> usize {
+        self.children.push(child);
+        self.children.len() - 1
+    }
+
+    pub fn task(&self) -> &ThrobberTask {
+        &self.task
+    }
+
+    pub fn task_mut(&mut self) -> &mut ThrobberTask {
+        &mut self.task
+    }
+
+    pub fn children(&self) -> &[ThrobberTreeNode] {
+        &self.children
+    }
+
+    pub fn children_mut(&mut self) -> &mut Vec<ThrobberTreeNode> {
+        &mut self.children
+    }
+
+    pub fn is_expanded(&self) -> bool {
+        !self.collapsed
+    }
+
+    /// Show or hide children.
+    pub fn set_expanded(&mut self, expanded: bool) {
+        self.collapsed = !expanded;
+    }
+
+    /// Switch between expanded and collapsed.
+    pub fn toggle(&mut self) {
+        self.collapsed = !self.collapsed;
+    }
+
+    /// Get the status derived from the task and its descendants.
+    ///
+    /// - If the task itself has an outcome, it is used.
+    /// - If any descendant is in progress, None.
+    /// - Otherwise, the most severe outcome of children.
+    ///   (Failure > Cancelled > Warning > Skipped > Success)
+    ///
+    /// # Examples:
+    /// ```
+    /// use throbber_widgets_tui::{Outcome, ThrobberTreeNode};
+    /// let mut deploy = ThrobberTreeNode::new("deploy")
+    ///     .child(ThrobberTreeNode::new("build"))
+    ///     .child(ThrobberTreeNode::new("upload"));
+    /// deploy.children_mut()[0].task_mut().finish(Outcome::Success);
+    /// assert_eq!(deploy.status(), None);
+    ///
+    /// deploy.children_mut()[1].task_mut().finish(Outcome::Warning);
+    /// assert_eq!(deploy.status(), Some(Outcome::Warning));
+    /// ```
+    pub fn status(&self) -> Option<Outcome> {
+        if let Some(outcome) = self.task.outcome() {
+            return Some(outcome);
+        }
+        if self.children.is_empty() {
+            return None;
+        }
+        let mut worst = Outcome::Success;
+        for child in &self.children {
+            let outcome = child.status()?;
+            if severity(worst) < severity(outcome) {
+                worst = outcome;
+            }
+        }
+        Some(worst)
+    }
+
+    /// Get the descendant node by the path of child indices.
+    pub fn node_mut(&mut self, path: &[usize]) -> Option<&mut ThrobberTreeNode> {
+        match path.split_first() {
+            None => Some(self),
+            Some((first, rest)) => self.children.get_mut(*first)?.node_mut(rest),
+        }
+    }
+
+    /// Calculate the index of the unfinished tasks recursively.
+    fn calc_step(&mut self, step: i64) {
+        if !self.task.is_finished() {
+            self.task.state_mut().calc_step(step);
+        }
+        self.children
+            .iter_mut()
+            .for_each(|child| child.calc_step(step));
+    }
+}
+
+/// Order of outcomes to aggregate children.
+fn severity(outcome: Outcome) -> u8 {
+    match outcome {
+        Outcome::Success => 0,
+        Outcome::Skipped => 1,
+        Outcome::Warning => 2,
+        Outcome::Cancelled => 3,
+        Outcome::Failure => 4,
+    }
+}
+
+/// State to be used for ThrobberTree render.
+///
+/// It owns the root nodes and the scroll offset.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ThrobberTreeState {
+    roots: Vec<ThrobberTreeNode>,
+    /// Index of the first visible row.
+    offset: usize,
+}
+
+impl ThrobberTreeState {
+    /// Add a root node and return its index.
+    pub fn push(&mut self, node: ThrobberTreeNode) -> usize {
+        self.roots.push(node);
+        self.roots.len() - 1
+    }
+
+    pub fn roots(&self) -> &[ThrobberTreeNode] {
+        &self.roots
+    }
+
+    pub fn roots_mut(&mut self) -> &mut Vec<ThrobberTreeNode> {
+        &mut self.roots
+    }
+
+    /// Get the node by the path of indices from the root.
+    ///
+    /// # Examples:
+    /// ```
+    /// use throbber_widgets_tui::{ThrobberTreeNode, ThrobberTreeState};
+    /// let mut tree_state = ThrobberTreeState::default();
+    /// tree_state.push(ThrobberTreeNode::new("deploy").child(ThrobberTreeNode::new("build")));
+    /// assert_eq!(tree_state.node_mut(&[0, 0]).unwrap().task().label(), "build");
+    /// assert!(tree_state.node_mut(&[0, 1]).is_none());
+    /// assert!(tree_state.node_mut(&[]).is_none());
+    /// ```
+    pub fn node_mut(&mut self, path: &[usize]) -> Option<&mut ThrobberTreeNode> {
+        let (first, rest) = path.split_first()?;
+        self.roots.get_mut(*first)?.node_mut(rest)
+    }
+
+    /// Switch the node between expanded and collapsed.
+    pub fn toggle(&mut self, path: &[usize]) {
+        if let Some(node) = self.node_mut(path) {
+            node.toggle();
+        }
+    }
+
+    /// Increase the index of all unfinished tasks.
+    pub fn calc_next(&mut self) {
+        self.calc_step(1);
+    }
+
+    /// Calculate the index of all unfinished tasks by specifying step.
+    ///
+    /// See `ThrobberState::calc_step()`.
+    pub fn calc_step(&mut self, step: impl Into<i64>) {
+        let step: i64 = step.into();
+        self.roots.iter_mut().for_each(|node| node.calc_step(step));
+    }
+
+    /// Get the index of the first visible row.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Set the index of the first visible row.
+    ///
+    /// If too large, it is clamped at render time.
+    pub fn set_offset(&mut self, offset: usize) {
+        self.offset = offset;
+    }
+
+    pub fn scroll_up(&mut self, amount: usize) {
+        self.offset = self.offset.saturating_sub(amount);
+    }
+
+    pub fn scroll_down(&mut self, amount: usize) {
+        self.offset = self.offset.saturating_add(amount);
+    }
+}
+
+/// A widget to display tasks and subtasks with throbbers as a tree.
+///
+/// Parents show the status aggregated from their children,
+/// and a marker whether their children are expanded (`▾`) or collapsed (`▸`).
+///
+/// # Examples:
+///
+/// ```
+/// use throbber_widgets_tui::{ThrobberTree, ThrobberTreeNode, ThrobberTreeState};
+/// let tree = ThrobberTree::default();
+/// let mut tree_state = ThrobberTreeState::default();
+/// tree_state.push(
+///     ThrobberTreeNode::new("deploy")
+///         .child(ThrobberTreeNode::new("build"))
+///         .child(ThrobberTreeNode::new("upload"))
+///         .child(ThrobberTreeNode::new("migrate")),
+/// );
+/// // frame.render_stateful_widget(tree, chunks[0], &mut tree_state);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ThrobberTree<'a> {
+    throbber: Throbber<'a>,
+    guide_style: ratatui::style::Style,
+    expanded_marker: &'a str,
+    collapsed_marker: &'a str,
+}
+
+impl Default for ThrobberTree<'_> {
+    fn default() -> Self {
+        Self {
+            throbber: Throbber::default().elapsed_format(super::ElapsedFormat::default()),
+            guide_style: ratatui::style::Style::default(),
+            expanded_marker: "▾ ",
+            collapsed_marker: "▸ ",
+        }
+    }
+}
+
+impl<'a> ThrobberTree<'a> {
+    /// Throbber used to render each node. Its label is replaced by the task label.
+    pub fn throbber(mut self, throbber: Throbber<'a>) -> Self {
+        self.throbber = throbber;
+        self
+    }
+
+    /// Style of the tree guides.
+    pub fn guide_style(mut self, style: ratatui::style::Style) -> Self {
+        self.guide_style = style;
+        self
+    }
+
+    /// Markers put before the nodes with children, styled with guide_style.
+    ///
+    /// Leaves are padded with spaces of the marker width.
+    /// The defaults are `"▾ "` for expanded nodes and `"▸ "` for collapsed nodes.
+    /// Use empty strings to hide them.
+    ///
+    /// # Examples:
+    /// ```
+    /// let tree = throbber_widgets_tui::ThrobberTree::default().markers("- ", "+ ");
+    /// ```
+    pub fn markers(mut self, expanded: &'a str, collapsed: &'a str) -> Self {
+        self.expanded_marker = expanded;
+        self.collapsed_marker = collapsed;
+        self
+    }
+
+    /// Get the display width of the wider marker.
+    fn marker_width(&self) -> usize {
+        display_width(self.expanded_marker).max(display_width(self.collapsed_marker))
+    }
+
+    /// Flatten the visible nodes into lines with tree guides.
+    fn push_lines<'b>(
+        &self,
+        nodes: &'b [ThrobberTreeNode],
+        prefix: &str,
+        is_root: bool,
+        lines: &mut Vec<ratatui::text::Line<'b>>,
+    ) where
+        'a: 'b,
+    {
+        for (i, node) in nodes.iter().enumerate() {
+            let is_last = i + 1 == nodes.len();
+            let (guide, child_prefix) = match (is_root, is_last) {
+                (true, _) => ("", String::new()),
+                (false, false) => ("├─ ", String::from(prefix) + "│  "),
+                (false, true) => ("└─ ", String::from(prefix) + "   "),
+            };
+            let mut state = node.task.state().clone();
+            state.set_outcome(node.status());
+            let marker = match (node.children.is_empty(), node.is_expanded()) {
+                (true, _) => "",
+                (false, true) => self.expanded_marker,
+                (false, false) => self.collapsed_marker,
+            };
+            // Pad the markers so that the throbbers of siblings are aligned.
+            let padding = self.marker_width().saturating_sub(display_width(marker));
+            let mut text = String::from(prefix) + guide + marker;
+            text.extend(core::iter::repeat_n(' ', padding));
+            let mut line = node.task.to_line(&self.throbber, &state);
+            line.spans
+                .insert(0, ratatui::text::Span::styled(text, self.guide_style));
+            lines.push(line);
+            if node.is_expanded() {
+                self.push_lines(&node.children, &child_prefix, false, lines);
+            }
+        }
+    }
+}
+
+impl ratatui::widgets::StatefulWidget for ThrobberTree<'_> {
+    type State = ThrobberTreeState;
+
+    /// Render visible nodes from the offset.
+    fn render(
+        self,
+        area: ratatui::layout::Rect,
+        buf: &mut ratatui::buffer::Buffer,
+        state: &mut Self::State,
+    ) {
+        buf.set_style(area, self.throbber.style);
+
+        let mut lines = Vec::new();
+        self.push_lines(&state.roots, "", true, &mut lines);
+
+        let height = area.height as usize;
+        let offset = state.offset.min(lines.len().saturating_sub(height));
+        for (i, line) in lines.iter().skip(offset).take(height).enumerate() {
+            buf.set_line(area.left(), area.top() + i as u16, line, area.width);
+        }
+        state.offset = offset;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn render(tree_state: &mut ThrobberTreeState, height: u16) -> Vec<String> {
+        crate::widgets::render_rows(ThrobberTree::default(), tree_state, height)
+    }
+
+    fn deploy() -> ThrobberTreeState {
+        let mut tree_state = ThrobberTreeState::default();
+        tree_state.push(
+            ThrobberTreeNode::new("deploy")
+                .child(ThrobberTreeNode::new("build").child(ThrobberTreeNode::new("lint")))
+                .child(ThrobberTreeNode::new("upload"))
+                .child(ThrobberTreeNode::new("migrate")),
+        );
+        tree_state
+    }
+
+    #[test]
+    fn throbber_tree_renders_guides() {
+        let mut tree_state = deploy();
+        let rows = render(&mut tree_state, 5);
+        assert_eq!(
+            rows,
+            [
+                "▾ ⠷ deploy",
+                "├─ ▾ ⠷ build",
+                "│  └─   ⠷ lint",
+                "├─   ⠷ upload",
+                "└─   ⠷ migrate",
+            ]
+        );
+
+        tree_state.toggle(&[0, 0]);
+        let rows = render(&mut tree_state, 5);
+        assert_eq!(rows[1], "├─ ▸ ⠷ build");
+        assert_eq!(rows[2], "├─   ⠷ upload");
+        assert_eq!(rows[4], "");
+    }
+
+    #[test]
+    fn throbber_tree_markers() {
+        let mut tree_state = deploy();
+        tree_state.toggle(&[0]);
+        let rows = render(&mut tree_state, 2);
+        assert_eq!(rows, ["▸ ⠷ deploy", ""]);
+
+        tree_state.toggle(&[0]);
+        let tree = ThrobberTree::default().markers("", "");
+        let rows = crate::widgets::render_rows(tree, &mut tree_state, 2);
+        assert_eq!(rows, ["⠷ deploy", "├─ ⠷ build"]);
+    }
+
+    #[test]
+    fn throbber_tree_aligns_leaves_with_parents() {
+        let mut tree_state = deploy();
+        tree_state.push(ThrobberTreeNode::new("notify"));
+        tree_state.toggle(&[0]);
+        let rows = render(&mut tree_state, 2);
+        assert_eq!(rows, ["▸ ⠷ deploy", "  ⠷ notify"]);
+
+        tree_state.toggle(&[0]);
+        let tree = ThrobberTree::default().markers("-", "+ ");
+        let rows = crate::widgets::render_rows(tree, &mut tree_state, 6);
+        assert_eq!(
+            rows,
+            [
+                "- ⠷ deploy",
+                "├─ - ⠷ build",
+                "│  └─   ⠷ lint",
+                "├─   ⠷ upload",
+                "└─   ⠷ migrate",
+                "  ⠷ notify",
+            ]
+        );
+    }
+
+    #[test]
+    fn throbber_tree_aggregates_status() {
+        let mut tree_state = deploy();
+        tree_state.calc_next();
+        for path in [[0, 1], [0, 2]] {
+            tree_state
+                .node_mut(&path)
+                .unwrap()
+                .task_mut()
+                .finish(Outcome::Success);
+        }
+        tree_state
+            .node_mut(&[0, 0, 0])
+            .unwrap()
+            .task_mut()
+            .finish(Outcome::Failure);
+
+        // "build" has no own outcome but all children are finished.
+        let rows = render(&mut tree_state, 5);
+        assert_eq!(rows[0], "▾ ✖ deploy");
+        assert_eq!(rows[1], "├─ ▾ ✖ build");
+
+        // Finished tasks are not ticked, but unfinished ones are.
+        tree_state.calc_next();
+        let deploy = &tree_state.roots()[0];
+        assert_eq!(deploy.task().state().index(), 2);
+        assert_eq!(deploy.children()[1].task().state().index(), 1);
+    }
+}
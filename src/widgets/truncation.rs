@@ -0,0 +1,188 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// How the label is shortened when it does not fit in the area.
+///
+/// The label includes the progress and the elapsed time.
+/// Graphemes are kept whole, and wide characters are not cut in half.
+///
+/// # Examples:
+/// ```
+/// use throbber_widgets_tui::{LabelTruncation, Throbber, ThrobberState};
+/// let throbber = Throbber::default()
+///     .label("src/widgets/main.rs")
+///     .label_truncation(LabelTruncation::Middle);
+/// let area = ratatui::layout::Rect::new(0, 0, 14, 1);
+/// let mut buf = ratatui::buffer::Buffer::empty(area);
+/// let mut throbber_state = ThrobberState::default();
+/// ratatui::widgets::StatefulWidget::render(throbber, area, &mut buf, &mut throbber_state);
+/// assert_eq!(buf, ratatui::buffer::Buffer::with_lines(["⠷ src/w…ain.rs"]));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum LabelTruncation {
+    /// Cut at the end of the area.
+    #[default]
+    Clip,
+    /// e.g. `Downlo…`
+    End,
+    /// e.g. `src/w…ain.rs`, for file paths.
+    Middle,
+}
+
+const ELLIPSIS: &str = "…";
+
+impl LabelTruncation {
+    /// Shorten spans to fit in width.
+    pub(crate) fn truncate<'a>(
+        self,
+        spans: Vec<ratatui::text::Span<'a>>,
+        width: usize,
+    ) -> Vec<ratatui::text::Span<'a>> {
+        if spans.iter().map(ratatui::text::Span::width).sum::<usize>() <= width {
+            return spans;
+        }
+        let graphemes: Vec<_> = spans
+            .iter()
+            .flat_map(|span| span.styled_graphemes(ratatui::style::Style::default()))
+            .collect();
+        let symbols = graphemes.iter().map(|grapheme| grapheme.symbol);
+        let (head, tail) = match self {
+            Self::Clip => (fitting(symbols, width), 0),
+            Self::End => (fitting(symbols, width.saturating_sub(1)), 0),
+            Self::Middle => {
+                let rest = width.saturating_sub(1);
+                let tail = fitting(symbols.clone().rev(), rest - rest / 2);
+                let tail_width: usize = graphemes[graphemes.len() - tail..]
+                    .iter()
+                    .map(|grapheme| crate::symbols::throbber::display_width(grapheme.symbol))
+                    .sum();
+                (fitting(symbols, rest - tail_width), tail)
+            }
+        };
+
+        let mut truncated = Vec::new();
+        for grapheme in &graphemes[..head] {
+            push(&mut truncated, grapheme.symbol, grapheme.style);
+        }
+        if self != Self::Clip && 0 < width {
+            let style = graphemes.get(head).map(|grapheme| grapheme.style);
+            push(&mut truncated, ELLIPSIS, style.unwrap_or_default());
+        }
+        for grapheme in &graphemes[graphemes.len() - tail..] {
+            push(&mut truncated, grapheme.symbol, grapheme.style);
+        }
+        truncated
+    }
+}
+
+/// Wrap spans at whitespace into rows of width.
+///
+/// Words longer than width are broken, and whitespace at the row ends is removed.
+pub(crate) fn wrap<'a>(
+    spans: Vec<ratatui::text::Span<'a>>,
+    width: usize,
+) -> Vec<Vec<ratatui::text::Span<'a>>> {
+    if width == 0 || spans.iter().map(ratatui::text::Span::width).sum::<usize>() <= width {
+        return alloc::vec![spans];
+    }
+    let is_blank = |symbol: &str| symbol.chars().all(char::is_whitespace);
+    let mut rows = Vec::new();
+    let mut row: Vec<ratatui::text::StyledGrapheme> = Vec::new();
+    let mut row_width = 0;
+    for grapheme in spans
+        .iter()
+        .flat_map(|span| span.styled_graphemes(ratatui::style::Style::default()))
+    {
+        let grapheme_width = crate::symbols::throbber::display_width(grapheme.symbol);
+        if width < row_width + grapheme_width && !row.is_empty() {
+            // Break at the last whitespace, or in the word if there is none.
+            let rest = match row.iter().rposition(|grapheme| is_blank(grapheme.symbol)) {
+                Some(blank) if !is_blank(grapheme.symbol) => row.split_off(blank + 1),
+                _ => Vec::new(),
+            };
+            while row.last().is_some_and(|grapheme| is_blank(grapheme.symbol)) {
+                row.pop();
+            }
+            rows.push(row);
+            row = rest;
+            row_width = row
+                .iter()
+                .map(|grapheme| crate::symbols::throbber::display_width(grapheme.symbol))
+                .sum();
+        }
+        if row.is_empty() && is_blank(grapheme.symbol) && !rows.is_empty() {
+            continue;
+        }
+        row_width += grapheme_width;
+        row.push(grapheme);
+    }
+    rows.push(row);
+    rows.into_iter()
+        .map(|row| {
+            let mut spans = Vec::new();
+            for grapheme in row {
+                push(&mut spans, grapheme.symbol, grapheme.style);
+            }
+            spans
+        })
+        .collect()
+}
+
+/// Get the number of graphemes from the head that fit in width.
+fn fitting<'g>(symbols: impl Iterator<Item = &'g str>, width: usize) -> usize {
+    let mut used = 0;
+    symbols
+        .take_while(|symbol| {
+            used += crate::symbols::throbber::display_width(symbol);
+            used <= width
+        })
+        .count()
+}
+
+/// Append the grapheme, joining it to the last span of the same style.
+fn push(spans: &mut Vec<ratatui::text::Span<'_>>, symbol: &str, style: ratatui::style::Style) {
+    match spans.last_mut() {
+        Some(last) if last.style == style => last.content.to_mut().push_str(symbol),
+        _ => spans.push(ratatui::text::Span::styled(String::from(symbol), style)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+
+    #[test]
+    fn label_truncation_keeps_graphemes_whole() {
+        let truncate = |truncation: LabelTruncation, label: &'static str, width| {
+            let spans = truncation.truncate(alloc::vec![ratatui::text::Span::raw(label)], width);
+            ratatui::text::Line::from(spans).to_string()
+        };
+        assert_eq!(truncate(LabelTruncation::End, "Loading", 7), "Loading");
+        assert_eq!(truncate(LabelTruncation::End, "Loading", 5), "Load…");
+        assert_eq!(truncate(LabelTruncation::End, "Loading", 0), "");
+        // A wide character that does not fit is dropped whole.
+        assert_eq!(truncate(LabelTruncation::Clip, "読み込み中", 5), "読み");
+        assert_eq!(truncate(LabelTruncation::End, "読み込み中", 6), "読み…");
+        assert_eq!(
+            truncate(LabelTruncation::End, "e\u{301}e\u{301}e\u{301}", 2),
+            "e\u{301}…"
+        );
+        assert_eq!(truncate(LabelTruncation::Middle, "abcdefgh", 6), "ab…fgh");
+        assert_eq!(truncate(LabelTruncation::Middle, "読み込み中", 6), "読…中");
+    }
+
+    #[test]
+    fn wrap_breaks_at_whitespace() {
+        let wrap = |label: &'static str, width| -> Vec<String> {
+            wrap(alloc::vec![ratatui::text::Span::raw(label)], width)
+                .into_iter()
+                .map(|row| ratatui::text::Line::from(row).to_string())
+                .collect()
+        };
+        assert_eq!(wrap("fetch crates", 12), ["fetch crates"]);
+        assert_eq!(wrap("fetch the crates", 9), ["fetch the", "crates"]);
+        assert_eq!(wrap("fetch  crates", 5), ["fetch", "crate", "s"]);
+        assert_eq!(wrap("読み込み中", 4), ["読み", "込み", "中"]);
+    }
+}